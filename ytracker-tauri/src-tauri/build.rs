@@ -12,9 +12,13 @@ fn main() {
     if let Ok(val) = env::var("YTRACKER_CLIENT_SECRET") {
         println!("cargo:rustc-env=YTRACKER_CLIENT_SECRET={}", val);
     }
+    if let Ok(val) = env::var("YTRACKER_BUILD_DATE") {
+        println!("cargo:rustc-env=YTRACKER_BUILD_DATE={}", val);
+    }
 
     println!("cargo:rerun-if-env-changed=YTRACKER_CLIENT_ID");
     println!("cargo:rerun-if-env-changed=YTRACKER_CLIENT_SECRET");
+    println!("cargo:rerun-if-env-changed=YTRACKER_BUILD_DATE");
 
     tauri_build::build()
 }
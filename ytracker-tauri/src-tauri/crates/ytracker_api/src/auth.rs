@@ -1,11 +1,30 @@
 //! OAuth token exchange helpers for Tracker authentication.
 
-use reqwest::Client;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::RngCore;
+use reqwest::{Client, StatusCode};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
 
 use crate::error::{Result, TrackerError};
 
 const TOKEN_URL: &str = "https://oauth.yandex.ru/token";
+const REVOKE_TOKEN_URL: &str = "https://oauth.yandex.ru/revoke_token";
+const TOKEN_INFO_URL: &str = "https://login.yandex.ru/info";
+const PKCE_VERIFIER_BYTES: usize = 32;
+const TOKEN_VALIDATION_TIMEOUT: Duration = Duration::from_secs(5);
+const TOKEN_VALIDATION_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Selects how an authorization code is exchanged for a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    /// Confidential client: the request carries the client secret.
+    ClientSecret,
+    /// Public client: the request carries a PKCE code verifier instead of a secret.
+    Pkce,
+}
 
 #[derive(Debug, Deserialize, Clone)]
 /// OAuth token response payload returned by Tracker auth endpoint.
@@ -57,9 +76,140 @@ async fn exchange_code_with_url(
     }
 }
 
+/// Checks whether an access token is still accepted by Yandex.
+///
+/// Returns `true` on success and `false` on 401; other failures are propagated. The token
+/// is accepted regardless of `client_id`, so rotating client credentials keeps existing
+/// sessions. The request goes through `proxy_url` (minus `no_proxy` hosts) when set and
+/// gives up after a few seconds.
+pub async fn validate_token(
+    token: &str,
+    _client_id: &str,
+    proxy_url: Option<&str>,
+    no_proxy: Option<&str>,
+) -> Result<bool> {
+    let mut builder = Client::builder()
+        .timeout(TOKEN_VALIDATION_TIMEOUT)
+        .connect_timeout(TOKEN_VALIDATION_CONNECT_TIMEOUT);
+    if let Some(proxy_url) = proxy_url {
+        let proxy = reqwest::Proxy::all(proxy_url)?
+            .no_proxy(no_proxy.and_then(reqwest::NoProxy::from_string));
+        builder = builder.proxy(proxy);
+    }
+    let client = builder
+        .build()
+        .map_err(|err| TrackerError::Other(err.to_string()))?;
+    validate_token_with_url(&client, TOKEN_INFO_URL, token).await
+}
+
+async fn validate_token_with_url(client: &Client, info_url: &str, token: &str) -> Result<bool> {
+    let response = client
+        .get(info_url)
+        .query(&[("format", "json")])
+        .header("Authorization", format!("OAuth {}", token))
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Ok(false);
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(TrackerError::http(status, None, body));
+    }
+    Ok(true)
+}
+
+/// Generates a PKCE `(code_verifier, code_challenge)` pair using the S256 method.
+pub fn generate_pkce_pair() -> (String, String) {
+    let mut bytes = [0u8; PKCE_VERIFIER_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let verifier = URL_SAFE_NO_PAD.encode(bytes);
+    let challenge = pkce_challenge(&verifier);
+    (verifier, challenge)
+}
+
+/// Derives the S256 code challenge for a PKCE code verifier.
+fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Exchanges OAuth authorization code for an access token using a PKCE code verifier.
+pub async fn exchange_code_pkce(
+    code: &str,
+    code_verifier: &str,
+    client_id: &str,
+) -> Result<TokenResponse> {
+    let client = Client::new();
+    exchange_code_pkce_with_url(&client, TOKEN_URL, code, code_verifier, client_id).await
+}
+
+async fn exchange_code_pkce_with_url(
+    client: &Client,
+    token_url: &str,
+    code: &str,
+    code_verifier: &str,
+    client_id: &str,
+) -> Result<TokenResponse> {
+    let response = client
+        .post(token_url)
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("code_verifier", code_verifier),
+            ("client_id", client_id),
+        ])
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        response.json::<TokenResponse>().await.map_err(TrackerError::from)
+    } else {
+        let body = response.text().await.unwrap_or_default();
+        Err(TrackerError::http(status, None, body))
+    }
+}
+
+/// Revokes an OAuth access token server-side so it can no longer be used.
+pub async fn revoke_token(token: &str, client_id: &str, client_secret: &str) -> Result<()> {
+    let client = Client::new();
+    revoke_token_with_url(&client, REVOKE_TOKEN_URL, token, client_id, client_secret).await
+}
+
+async fn revoke_token_with_url(
+    client: &Client,
+    revoke_url: &str,
+    token: &str,
+    client_id: &str,
+    client_secret: &str,
+) -> Result<()> {
+    let response = client
+        .post(revoke_url)
+        .form(&[
+            ("access_token", token),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+        ])
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        let body = response.text().await.unwrap_or_default();
+        Err(TrackerError::http(status, None, body))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::exchange_code_with_url;
+    use super::{
+        exchange_code_pkce_with_url, exchange_code_with_url, generate_pkce_pair, pkce_challenge,
+        revoke_token_with_url, validate_token_with_url,
+    };
     use crate::error::TrackerError;
     use mockito::{Matcher, Server};
     use reqwest::Client;
@@ -121,11 +271,122 @@ mod tests {
         .await;
 
         match result {
-            Err(TrackerError::Http { status, message, .. }) => {
+            Err(TrackerError::Http {
+                status, message, ..
+            }) => {
                 assert_eq!(status, StatusCode::BAD_REQUEST);
                 assert_eq!(message, "invalid_grant");
             }
             other => panic!("unexpected result: {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn revoke_token_posts_token_and_credentials() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/revoke_token")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("access_token".into(), "token-xyz".into()),
+                Matcher::UrlEncoded("client_id".into(), "client-1".into()),
+                Matcher::UrlEncoded("client_secret".into(), "secret-1".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"status":"ok"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new();
+        revoke_token_with_url(
+            &client,
+            &format!("{}/revoke_token", server.url()),
+            "token-xyz",
+            "client-1",
+            "secret-1",
+        )
+        .await
+        .expect("revoke should succeed");
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn pkce_challenge_matches_rfc_example() {
+        // RFC 7636, Appendix B.
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+
+        let (verifier, challenge) = generate_pkce_pair();
+        assert_eq!(verifier.len(), 43);
+        assert_eq!(challenge, pkce_challenge(&verifier));
+    }
+
+    #[tokio::test]
+    async fn exchange_code_pkce_sends_code_verifier() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/token")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+                Matcher::UrlEncoded("code".into(), "abc".into()),
+                Matcher::UrlEncoded("code_verifier".into(), "verifier-1".into()),
+                Matcher::UrlEncoded("client_id".into(), "client-1".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"access_token":"token-xyz"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new();
+        let response = exchange_code_pkce_with_url(
+            &client,
+            &format!("{}/token", server.url()),
+            "abc",
+            "verifier-1",
+            "client-1",
+        )
+        .await
+        .expect("exchange should succeed");
+
+        assert_eq!(response.access_token, "token-xyz");
+    }
+
+    #[tokio::test]
+    async fn validate_token_maps_status() {
+        let mut server = Server::new_async().await;
+        let _valid = server
+            .mock("GET", "/info")
+            .match_query(Matcher::UrlEncoded("format".into(), "json".into()))
+            .match_header("authorization", "OAuth good")
+            .with_status(200)
+            .with_body(r#"{"id":"1","client_id":"client-1"}"#)
+            .create_async()
+            .await;
+        let _expired = server
+            .mock("GET", "/info")
+            .match_query(Matcher::Any)
+            .match_header("authorization", "OAuth expired")
+            .with_status(401)
+            .create_async()
+            .await;
+        let _broken = server
+            .mock("GET", "/info")
+            .match_query(Matcher::Any)
+            .match_header("authorization", "OAuth broken")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let client = Client::new();
+        let url = format!("{}/info", server.url());
+        let valid = validate_token_with_url(&client, &url, "good").await;
+        let expired = validate_token_with_url(&client, &url, "expired").await;
+        let broken = validate_token_with_url(&client, &url, "broken").await;
+
+        assert!(valid.expect("valid token"));
+        assert!(!expired.expect("expired token"));
+        assert!(broken.is_err());
+    }
 }
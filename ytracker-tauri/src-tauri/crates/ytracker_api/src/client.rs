@@ -1,26 +1,40 @@
 //! HTTP client wrapper for Yandex Tracker endpoints.
 
 use crate::config::TrackerConfig;
-use crate::error::{Result, TrackerError};
+use crate::error::{Result, TrackerError, TrackerErrorCode};
 use crate::models::{
     AttachmentMetadata,
+    BulkChangeResponse,
+    ChangelogEntry,
     ChecklistItem as TrackerChecklistItem,
     ChecklistItemCreate,
     ChecklistItemUpdate,
     Comment as TrackerComment,
+    FieldDefinition,
     Issue as TrackerIssue,
     IssueCreateRequest,
+    IssueLink,
+    LinkedIssue,
+    Queue,
     SimpleEntityRaw,
     Transition as TrackerTransition,
+    TransitionWithFields,
     UserProfile,
     WorklogEntry as TrackerWorklogEntry,
 };
 use crate::rate_limiter::RateLimiter;
+use async_stream::try_stream;
+use chrono::{DateTime, Utc};
+use futures_core::Stream;
+use log::debug;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
-use reqwest::{Client as HttpClient, Method, Response, StatusCode, Url};
+use reqwest::{Client as HttpClient, Method, RequestBuilder, Response, StatusCode, Url};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::{Map as JsonMap, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[derive(Clone)]
 /// High-level Tracker API client with typed request/response helpers.
@@ -28,33 +42,52 @@ pub struct TrackerClient {
     http: HttpClient,
     config: TrackerConfig,
     limiter: RateLimiter,
+    connectivity: Arc<Mutex<Option<bool>>>,
 }
 
 const FILTER_PAGE_LIMIT: u32 = 10;
 const FILTER_PAGE_SIZE: u32 = 200;
+const DEBUG_BODY_LIMIT: usize = 4 * 1024;
+const CONNECTIVITY_TIMEOUT: Duration = Duration::from_secs(5);
+const SEARCH_ALL_PER_SCROLL: u32 = 500;
 
 impl TrackerClient {
     /// Creates a client with HTTP transport and default per-config rate limiter.
     pub fn new(config: TrackerConfig) -> Result<Self> {
+        validate_config(&config)?;
         let http = build_http_client(&config)?;
         let limiter = RateLimiter::new(config.cooldown);
         Ok(Self {
             http,
             config,
             limiter,
+            connectivity: Arc::default(),
         })
     }
 
     /// Creates a client with externally provided limiter instance.
     pub fn new_with_limiter(config: TrackerConfig, limiter: RateLimiter) -> Result<Self> {
+        validate_config(&config)?;
         let http = build_http_client(&config)?;
         Ok(Self {
             http,
             config,
             limiter,
+            connectivity: Arc::default(),
         })
     }
 
+    /// Shares the last connectivity result with other clients built from the same cache.
+    pub fn with_connectivity_cache(mut self, cache: Arc<Mutex<Option<bool>>>) -> Self {
+        self.connectivity = cache;
+        self
+    }
+
+    /// Returns the result of the last connectivity check, or `None` if none ran yet.
+    pub fn last_connectivity(&self) -> Option<bool> {
+        *self.connectivity.lock().unwrap()
+    }
+
     /// Returns immutable client configuration.
     pub fn config(&self) -> &TrackerConfig {
         &self.config
@@ -100,6 +133,16 @@ impl TrackerClient {
         self.send_with_body(Method::POST, path, Some(body)).await
     }
 
+    /// Sends a typed POST request with JSON body and a per-request timeout override.
+    pub async fn post_with_timeout<B, T>(&self, path: &str, body: &B, timeout: Duration) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.send_with_body_and_timeout(Method::POST, path, Some(body), timeout)
+            .await
+    }
+
     /// Sends a typed PATCH request with JSON body.
     pub async fn patch<B, T>(&self, path: &str, body: &B) -> Result<T>
     where
@@ -116,6 +159,37 @@ impl TrackerClient {
 
     /// Generic typed request helper for methods with optional JSON body.
     pub async fn send_with_body<B, T>(&self, method: Method, path: &str, body: Option<&B>) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.send_with_optional_timeout(method, path, body, None)
+            .await
+    }
+
+    /// Generic typed request helper overriding the client-wide timeout for a single request.
+    pub async fn send_with_body_and_timeout<B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        timeout: Duration,
+    ) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.send_with_optional_timeout(method, path, body, Some(timeout))
+            .await
+    }
+
+    async fn send_with_optional_timeout<B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        timeout: Option<Duration>,
+    ) -> Result<T>
     where
         B: Serialize + ?Sized,
         T: DeserializeOwned,
@@ -123,10 +197,18 @@ impl TrackerClient {
         self.limiter.hit().await;
         let url = self.url_for(path);
         let mut request = self.http.request(method, url);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
         if let Some(payload) = body {
             request = request.json(payload);
         }
-        let response = request.send().await?;
+        let response = self.execute(request).await?;
+        if self.config.debug_requests && response.status().is_success() {
+            let body = response.text().await?;
+            log_response_body(&body);
+            return Ok(serde_json::from_str(&body)?);
+        }
         Self::parse_json(response).await
     }
 
@@ -141,10 +223,28 @@ impl TrackerClient {
         if let Some(payload) = body {
             request = request.json(payload);
         }
-        let response = request.send().await?;
+        let response = self.execute(request).await?;
+        if self.config.debug_requests && response.status().is_success() {
+            log_response_body(&response.text().await.unwrap_or_default());
+            return Ok(());
+        }
         Self::ensure_success(response).await
     }
 
+    /// Sends a prepared request, logging method, redacted URL and status when `debug_requests` is on.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        if !self.config.debug_requests {
+            return Ok(request.send().await?);
+        }
+        let request = request.build()?;
+        let method = request.method().clone();
+        let url = redact_url(request.url());
+        debug!("tracker request: {} {}", method, url);
+        let response = self.http.execute(request).await?;
+        debug!("tracker response: {} {} -> {}", method, url, response.status());
+        Ok(response)
+    }
+
     /// Builds an API URL from relative Tracker endpoint path.
     fn url_for(&self, path: &str) -> String {
         let mut base = self.config.api_root();
@@ -207,6 +307,25 @@ impl TrackerClient {
         }
     }
 
+    /// Pings `GET myself` with a short timeout and records whether Tracker was reachable.
+    ///
+    /// Only transport failures count as offline; an HTTP error response proves the
+    /// API is reachable and is returned unchanged.
+    pub async fn check_connectivity(&self) -> Result<()> {
+        let result: Result<Value> = self
+            .send_with_body_and_timeout(Method::GET, "myself", None::<&Value>, CONNECTIVITY_TIMEOUT)
+            .await;
+        let offline = matches!(result, Err(TrackerError::Network(_) | TrackerError::Timeout(_)));
+        *self.connectivity.lock().unwrap() = Some(!offline);
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) if offline => Err(TrackerError::Network(format!(
+                "Yandex Tracker is unreachable, check your internet connection ({err})"
+            ))),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Returns profile of the currently authenticated Tracker user.
     pub async fn get_myself(&self) -> Result<UserProfile> {
         self.get("myself").await
@@ -214,8 +333,101 @@ impl TrackerClient {
 
     /// Loads a single issue with summary/detail fields used by desktop UI.
     pub async fn get_issue(&self, issue_key: &str) -> Result<TrackerIssue> {
+        self.get_issue_with_fields(issue_key, ISSUE_SUMMARY_FIELDS).await
+    }
+
+    /// Loads a single issue restricted to the given comma-separated field projection.
+    pub async fn get_issue_with_fields(
+        &self,
+        issue_key: &str,
+        fields: &str,
+    ) -> Result<TrackerIssue> {
+        let path = format!("issues/{}", issue_key);
+        self.get_with_query(&path, Some(&[("fields", fields)])).await
+    }
+
+    /// Returns raw issue fields not covered by the typed summary model.
+    ///
+    /// Fetches the issue without a `fields` filter and drops every key listed in
+    /// the summary projection, leaving queue-specific custom fields (epics,
+    /// custom enumerations, etc.) as untyped JSON values.
+    pub async fn get_issue_custom_fields(&self, issue_key: &str) -> Result<HashMap<String, Value>> {
+        let path = format!("issues/{}", issue_key);
+        let raw: JsonMap<String, Value> = self.get(&path).await?;
+        Ok(raw
+            .into_iter()
+            .filter(|(key, _)| !is_summary_field(key))
+            .collect())
+    }
+
+    /// Sets a single issue field by key, e.g. a queue-specific custom field.
+    pub async fn update_issue_custom_field(
+        &self,
+        issue_key: &str,
+        field_key: &str,
+        value: Value,
+    ) -> Result<()> {
+        let path = format!("issues/{}", issue_key);
+        let mut payload = JsonMap::new();
+        payload.insert(field_key.to_string(), value);
+        self.send_expect_empty(Method::PATCH, &path, Some(&payload)).await
+    }
+
+    /// Moves an issue to another queue; Tracker assigns the issue a new key in the target queue.
+    pub async fn move_issue_to_queue(&self, issue_key: &str, target_queue_key: &str) -> Result<()> {
+        let path = format!("issues/{}", issue_key);
+        let payload = serde_json::json!({ "queue": { "key": target_queue_key } });
+        self.send_expect_empty(Method::PATCH, &path, Some(&payload)).await
+    }
+
+    /// Returns the issue's tags, or an empty list when it has none.
+    pub async fn get_issue_tags(&self, issue_key: &str) -> Result<Vec<String>> {
+        let issue = self.get_issue_with_fields(issue_key, "tags").await?;
+        Ok(issue.tags.unwrap_or_default())
+    }
+
+    /// Replaces the issue's whole tag list; pass an empty slice to clear it.
+    pub async fn set_issue_tags(&self, issue_key: &str, tags: &[&str]) -> Result<()> {
         let path = format!("issues/{}", issue_key);
-        self.get_with_query(&path, Some(&[("fields", ISSUE_SUMMARY_FIELDS)])).await
+        let payload = serde_json::json!({ "tags": tags });
+        self.send_expect_empty(Method::PATCH, &path, Some(&payload)).await
+    }
+
+    /// Adds the current user's vote to an issue.
+    pub async fn vote_for_issue(&self, issue_key: &str) -> Result<()> {
+        let path = format!("issues/{}/votes", issue_key);
+        self.send_expect_empty(Method::POST, &path, None::<&Value>).await
+    }
+
+    /// Withdraws the current user's vote from an issue.
+    pub async fn unvote_issue(&self, issue_key: &str) -> Result<()> {
+        let path = format!("issues/{}/votes", issue_key);
+        self.delete(&path).await
+    }
+
+    /// Subscribes the current user to issue notifications without touching the followers list.
+    pub async fn subscribe_to_issue(&self, issue_key: &str) -> Result<()> {
+        let path = format!("issues/{}/subscribers", issue_key);
+        self.send_expect_empty(Method::POST, &path, None::<&Value>).await
+    }
+
+    /// Removes the current user's notification subscription from an issue.
+    pub async fn unsubscribe_from_issue(&self, issue_key: &str) -> Result<()> {
+        let path = format!("issues/{}/subscribers", issue_key);
+        self.delete(&path).await
+    }
+
+    /// Applies the same field patch to several issues in one asynchronous bulk operation.
+    pub async fn bulk_update_issue_fields(
+        &self,
+        keys: &[&str],
+        patch: &Value,
+    ) -> Result<BulkChangeResponse> {
+        let payload = BulkChangeRequest {
+            issues: keys,
+            values: patch,
+        };
+        self.post("issues/_bulkchange", &payload).await
     }
 
     /// Performs issue search via POST endpoint with optional query/filter payload.
@@ -226,7 +438,7 @@ impl TrackerClient {
         let paging_params = [
             ("perPage", per_page.to_string()),
             ("page", "1".to_string()),
-            ("fields", ISSUE_SUMMARY_FIELDS.to_string()),
+            ("fields", params.fields_projection().to_string()),
         ];
         let payload = IssueSearchRequest::from_params(params);
         let response = self
@@ -239,6 +451,28 @@ impl TrackerClient {
         Self::parse_json(response).await
     }
 
+    /// Returns how many issues match the search using a single one-item page.
+    ///
+    /// The count comes from the `X-Total-Count` response header.
+    pub async fn get_issue_count(&self, params: &IssueSearchParams) -> Result<u64> {
+        self.limiter.hit().await;
+        let url = format!("{}issues/_search", self.config.api_root());
+        let paging_params = [("perPage", "1"), ("page", "1"), ("fields", "key")];
+        let payload = IssueSearchRequest::from_params(params);
+        let response = self
+            .http
+            .post(url)
+            .query(&paging_params)
+            .json(&payload)
+            .send()
+            .await?;
+
+        let (headers, _): (HeaderMap, Vec<Value>) = parse_json_with_headers(response).await?;
+        header_string(&headers, "X-Total-Count")
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| TrackerError::Other("Search response has no X-Total-Count header".into()))
+    }
+
     /// Performs scroll-based issue search and returns next-scroll metadata from headers.
     pub async fn search_issues_scroll(
         &self,
@@ -250,7 +484,7 @@ impl TrackerClient {
     ) -> Result<ScrollPage<TrackerIssue>> {
         self.limiter.hit().await;
         let url = format!("{}issues/_search", self.config.api_root());
-        let mut request_params = vec![("fields", ISSUE_SUMMARY_FIELDS.to_string())];
+        let mut request_params = vec![("fields", params.fields_projection().to_string())];
 
         if let Some(id) = scroll_id {
             request_params.push(("scrollId", id.to_string()));
@@ -284,12 +518,108 @@ impl TrackerClient {
         })
     }
 
+    /// Streams every issue matching `params`, following the scroll id from page to page.
+    ///
+    /// The stream ends after the first empty page or a page without a scroll id, and the
+    /// scroll context is released once all pages are read. A failed request ends the stream
+    /// with that error.
+    pub fn search_issues_all<'a>(
+        &'a self,
+        params: &'a IssueSearchParams,
+    ) -> impl Stream<Item = Result<TrackerIssue>> + 'a {
+        try_stream! {
+            let mut scroll_id: Option<String> = None;
+            loop {
+                let page = self
+                    .search_issues_scroll(
+                        params,
+                        scroll_id.as_deref(),
+                        Some(SEARCH_ALL_PER_SCROLL),
+                        ScrollType::Unsorted,
+                        None,
+                    )
+                    .await?;
+                let last_page = page.items.is_empty() || page.scroll_id.is_none();
+                scroll_id = page.scroll_id;
+                for issue in page.items {
+                    yield issue;
+                }
+                if last_page {
+                    break;
+                }
+            }
+            if let Some(id) = scroll_id {
+                if let Err(err) = self.clear_scroll_context(&id).await {
+                    debug!("Failed to release exhausted scroll context: {}", err);
+                }
+            }
+        }
+    }
+
+    /// Fetches the page behind an existing scroll id ahead of time, e.g. while page N is displayed.
+    ///
+    /// The client keeps no state between calls, so holding the prefetched page until it is
+    /// requested is up to the caller.
+    pub async fn prefetch_scroll_page(
+        &self,
+        params: &IssueSearchParams,
+        scroll_id: &str,
+        per_page: u32,
+    ) -> Result<ScrollPage<TrackerIssue>> {
+        self.search_issues_scroll(params, Some(scroll_id), Some(per_page), ScrollType::Sorted, None)
+            .await
+    }
+
     /// Returns all comments for a specific issue.
     pub async fn get_issue_comments(&self, issue_key: &str) -> Result<Vec<TrackerComment>> {
         let path = format!("issues/{}/comments", issue_key);
         self.get(&path).await
     }
 
+    /// Returns a single comment of a specific issue.
+    pub async fn get_comment(&self, issue_key: &str, comment_id: &str) -> Result<TrackerComment> {
+        let path = format!("issues/{}/comments/{}", issue_key, comment_id);
+        self.get(&path).await
+    }
+
+    /// Returns change history for a specific issue; a `null` payload is treated as no history.
+    pub async fn get_issue_changelog(&self, issue_key: &str) -> Result<Vec<ChangelogEntry>> {
+        let path = format!("issues/{}/changelog", issue_key);
+        let entries: Option<Vec<ChangelogEntry>> =
+            self.get_with_query(&path, Some(&[("perPage", "100")])).await?;
+        Ok(entries.unwrap_or_default())
+    }
+
+    /// Returns raw link metadata for a specific issue.
+    pub async fn get_issue_links(&self, issue_key: &str) -> Result<Vec<IssueLink>> {
+        let path = format!("issues/{}/links", issue_key);
+        self.get(&path).await
+    }
+
+    /// Returns linked issues with full details, fetching each linked issue individually.
+    pub async fn get_issue_linked_issues(&self, issue_key: &str) -> Result<Vec<LinkedIssue>> {
+        let links = self.get_issue_links(issue_key).await?;
+        let mut linked = Vec::with_capacity(links.len());
+        for link in links {
+            let Some(target_key) = link.object.as_ref().and_then(|object| object.key.clone()) else {
+                continue;
+            };
+            let relation_type = link
+                .link_type
+                .as_ref()
+                .and_then(|link_type| link_type.id.clone().or_else(|| link_type.key.clone()))
+                .unwrap_or_else(|| "relates".to_string());
+            let direction = link.direction.unwrap_or_else(|| "outward".to_string());
+            let issue = self.get_issue(&target_key).await?;
+            linked.push(LinkedIssue {
+                relation_type,
+                direction,
+                issue,
+            });
+        }
+        Ok(linked)
+    }
+
     /// Returns attachment metadata list for a specific issue.
     pub async fn get_issue_attachments(&self, issue_key: &str) -> Result<Vec<AttachmentMetadata>> {
         let path = format!("issues/{}/attachments", issue_key);
@@ -316,7 +646,13 @@ impl TrackerClient {
 
         let form = reqwest::multipart::Form::new().part("file", part);
 
-        let response = self.http.post(url).multipart(form).send().await?;
+        let response = self
+            .http
+            .post(url)
+            .timeout(self.config.upload_timeout)
+            .multipart(form)
+            .send()
+            .await?;
         Self::parse_json(response).await
     }
 
@@ -338,7 +674,13 @@ impl TrackerClient {
 
         let form = reqwest::multipart::Form::new().part("file", part);
 
-        let response = self.http.post(url).multipart(form).send().await?;
+        let response = self
+            .http
+            .post(url)
+            .timeout(self.config.upload_timeout)
+            .multipart(form)
+            .send()
+            .await?;
         Self::parse_json(response).await
     }
 
@@ -359,6 +701,28 @@ impl TrackerClient {
         self.send_expect_empty(Method::POST, &path, Some(&payload)).await
     }
 
+    /// Adds an emoji reaction to an issue comment.
+    pub async fn add_comment_reaction(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+        emoji: &str,
+    ) -> Result<()> {
+        let path = format!("issues/{}/comments/{}/reactions/{}", issue_key, comment_id, emoji);
+        self.send_expect_empty(Method::POST, &path, None::<&Value>).await
+    }
+
+    /// Removes an emoji reaction from an issue comment.
+    pub async fn remove_comment_reaction(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+        emoji: &str,
+    ) -> Result<()> {
+        let path = format!("issues/{}/comments/{}/reactions/{}", issue_key, comment_id, emoji);
+        self.delete(&path).await
+    }
+
     /// Updates mutable issue fields (currently summary and description).
     pub async fn update_issue_fields(
         &self,
@@ -389,6 +753,37 @@ impl TrackerClient {
         self.post("issues", payload).await
     }
 
+    /// Creates a new issue copying summary, description, type, priority and queue of `source_key`.
+    ///
+    /// Watchers, worklogs, attachments, links and custom fields are not copied. The queue
+    /// is taken from the source key prefix; `new_summary` overrides the copied summary.
+    pub async fn clone_issue(&self, source_key: &str, new_summary: Option<&str>) -> Result<TrackerIssue> {
+        let source = self.get_issue(source_key).await?;
+        let queue = source
+            .key
+            .split_once('-')
+            .map(|(queue, _)| queue.to_string())
+            .filter(|queue| !queue.is_empty())
+            .ok_or_else(|| {
+                TrackerError::Other(format!("cannot determine queue of issue {}", source.key))
+            })?;
+
+        let payload = IssueCreateRequest {
+            queue,
+            summary: new_summary
+                .map(ToString::to_string)
+                .or(source.summary)
+                .unwrap_or_default(),
+            description: source.description,
+            issue_type: source.issue_type.as_ref().and_then(|value| value.key()),
+            priority: source.priority.as_ref().and_then(|value| value.key()),
+            assignee: None,
+            project: None,
+            attachment_ids: None,
+        };
+        self.create_issue(&payload).await
+    }
+
     /// Returns global priority directory entries.
     pub async fn get_priorities(&self) -> Result<Vec<SimpleEntityRaw>> {
         self.get("priorities").await
@@ -396,7 +791,36 @@ impl TrackerClient {
 
     /// Returns global issue type directory entries.
     pub async fn get_issue_types(&self) -> Result<Vec<SimpleEntityRaw>> {
-        self.get("issuetypes").await
+        self.list_all_issue_types(None).await
+    }
+
+    /// Returns issue types, optionally limited to those available in a queue.
+    pub async fn list_all_issue_types(
+        &self,
+        queue_key: Option<&str>,
+    ) -> Result<Vec<SimpleEntityRaw>> {
+        match queue_key {
+            Some(queue) => self.get_with_query("issuetypes", Some(&[("queue", queue)])).await,
+            None => self.get("issuetypes").await,
+        }
+    }
+
+    /// Returns the issue types configured in a queue's workflows.
+    pub async fn get_queue_issue_types(&self, queue_key: &str) -> Result<Vec<SimpleEntityRaw>> {
+        let path = format!("queues/{}/issuetypes", queue_key);
+        self.get(&path).await
+    }
+
+    /// Returns components defined in a queue.
+    pub async fn get_queue_components(&self, queue_key: &str) -> Result<Vec<SimpleEntityRaw>> {
+        let path = format!("queues/{}/components", queue_key);
+        self.get(&path).await
+    }
+
+    /// Returns field definitions (type, required flag, allowed values) available in a queue.
+    pub async fn get_queue_fields(&self, queue_key: &str) -> Result<Vec<FieldDefinition>> {
+        let path = format!("queues/{}/fields", queue_key);
+        self.get(&path).await
     }
 
     /// Returns available workflow transitions for an issue.
@@ -405,6 +829,15 @@ impl TrackerClient {
         self.get(&path).await
     }
 
+    /// Returns available transitions including the fields each transition screen asks for.
+    pub async fn get_transitions_with_fields(
+        &self,
+        issue_key: &str,
+    ) -> Result<Vec<TransitionWithFields>> {
+        let path = format!("issues/{}/transitions", issue_key);
+        self.get(&path).await
+    }
+
     /// Executes a workflow transition with optional comment and resolution.
     pub async fn execute_transition(
         &self,
@@ -412,13 +845,30 @@ impl TrackerClient {
         transition_id: &str,
         comment: Option<&str>,
         resolution: Option<&str>,
+    ) -> Result<()> {
+        let mut fields = HashMap::new();
+        if let Some(comment) = comment {
+            fields.insert("comment".to_string(), Value::String(comment.to_string()));
+        }
+        if let Some(resolution) = resolution {
+            fields.insert("resolution".to_string(), Value::String(resolution.to_string()));
+        }
+        self.execute_transition_with_fields(issue_key, transition_id, &fields)
+            .await
+    }
+
+    /// Executes a workflow transition sending arbitrary transition screen fields in the body.
+    pub async fn execute_transition_with_fields(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+        fields: &HashMap<String, Value>,
     ) -> Result<()> {
         let path = format!(
             "issues/{}/transitions/{}/_execute",
             issue_key, transition_id
         );
-        let payload = TransitionExecuteRequest { comment, resolution };
-        self.send_expect_empty(Method::POST, &path, Some(&payload)).await
+        self.send_expect_empty(Method::POST, &path, Some(fields)).await
     }
 
     /// Writes a worklog entry to issue history.
@@ -484,6 +934,35 @@ impl TrackerClient {
         Ok(result)
     }
 
+    /// Returns an issue's worklogs whose start (or creation time) falls in `[from, to)`.
+    ///
+    /// The issue worklog endpoint has no date filter, so entries are filtered after loading.
+    pub async fn get_issue_worklogs_in_range(
+        &self,
+        issue_key: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TrackerWorklogEntry>> {
+        if from >= to {
+            return Err(TrackerError::Other(
+                "Worklog range start must be before its end".into(),
+            ));
+        }
+        let entries = self.get_issue_worklogs(issue_key).await?;
+        Ok(entries
+            .into_iter()
+            .filter(|entry| {
+                entry
+                    .start
+                    .as_deref()
+                    .or(entry.created_at.as_deref())
+                    .and_then(parse_tracker_timestamp)
+                    .map(|at| at >= from && at < to)
+                    .unwrap_or(false)
+            })
+            .collect())
+    }
+
     /// Searches worklogs by optional creator and created-at range constraints.
     pub async fn get_worklogs_by_params(
         &self,
@@ -577,11 +1056,36 @@ impl TrackerClient {
             .await
     }
 
+    /// Clears several scroll contexts one at a time through the rate limiter.
+    ///
+    /// Every id is attempted even if an earlier one fails; the first error is returned.
+    pub async fn clear_scroll_contexts(&self, scroll_ids: &[&str]) -> Result<()> {
+        let mut first_error = None;
+        for scroll_id in scroll_ids
+            .iter()
+            .map(|id| id.trim())
+            .filter(|id| !id.is_empty())
+        {
+            if let Err(err) = self.clear_scroll_context(scroll_id).await {
+                first_error.get_or_insert(err);
+            }
+        }
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
     /// Downloads arbitrary binary resource referenced by absolute or relative URL.
     pub async fn fetch_binary(&self, href: &str) -> Result<BinaryContent> {
         self.limiter.hit().await;
         let url = self.absolute_url(href)?;
-        let response = self.http.get(url).send().await?;
+        let response = self
+            .http
+            .get(url)
+            .timeout(self.config.upload_timeout)
+            .send()
+            .await?;
         let status = response.status();
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
@@ -601,6 +1105,12 @@ impl TrackerClient {
         self.fetch_simple_entity_pages("queues").await
     }
 
+    /// Returns details of a single queue, including its lead and default issue type.
+    pub async fn get_queue(&self, queue_key: &str) -> Result<Queue> {
+        let path = format!("queues/{}", queue_key);
+        self.get(&path).await
+    }
+
     /// Returns full projects directory by traversing paged endpoint.
     pub async fn list_all_projects(&self) -> Result<Vec<SimpleEntityRaw>> {
         self.fetch_simple_entity_pages("projects").await
@@ -709,14 +1219,67 @@ fn build_http_client(config: &TrackerConfig) -> Result<HttpClient> {
         headers.insert(header_name, header_value(org_id.clone())?);
     }
 
-    HttpClient::builder()
+    let mut builder = HttpClient::builder()
         .default_headers(headers)
         .timeout(config.timeout)
-        .connect_timeout(config.connect_timeout)
+        .connect_timeout(config.connect_timeout);
+
+    if let Some(proxy_url) = &config.proxy_url {
+        let proxy = reqwest::Proxy::all(proxy_url)?
+            .no_proxy(config.no_proxy.as_deref().and_then(reqwest::NoProxy::from_string));
+        builder = builder.proxy(proxy);
+    }
+
+    builder
         .build()
         .map_err(|err| TrackerError::Other(err.to_string()))
 }
 
+/// Renders a URL for logs with values of token-like query parameters masked.
+fn redact_url(url: &Url) -> String {
+    if url.query().is_none() {
+        return url.to_string();
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if key.to_ascii_lowercase().contains("token") {
+                "<redacted>".to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    let mut redacted = url.clone();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+/// Logs a success response body, truncated to `DEBUG_BODY_LIMIT` bytes on a char boundary.
+fn log_response_body(body: &str) {
+    if body.len() <= DEBUG_BODY_LIMIT {
+        debug!("tracker response body: {}", body);
+        return;
+    }
+    let mut end = DEBUG_BODY_LIMIT;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    debug!(
+        "tracker response body ({} bytes, truncated): {}",
+        body.len(),
+        &body[..end]
+    );
+}
+
+/// Runs config validation and folds all violations into a single error.
+fn validate_config(config: &TrackerConfig) -> Result<()> {
+    config
+        .validate()
+        .map_err(|errors| TrackerError::Other(format!("invalid tracker config: {}", errors.join("; "))))
+}
+
 /// Converts string into HTTP header value with consistent error mapping.
 fn header_value(value: String) -> Result<HeaderValue> {
     HeaderValue::from_str(&value).map_err(|err| TrackerError::Other(err.to_string()))
@@ -729,10 +1292,10 @@ fn build_http_error(status: StatusCode, body: &str) -> TrackerError {
 }
 
 /// Attempts to extract API-specific error code from JSON response body.
-fn extract_error_code(body: &str) -> Option<String> {
+fn extract_error_code(body: &str) -> Option<TrackerErrorCode> {
     serde_json::from_str::<Value>(body)
         .ok()
-        .and_then(|value| value.get("code").and_then(|c| c.as_str()).map(|s| s.to_string()))
+        .and_then(|value| value.get("code").and_then(|c| c.as_str()).map(TrackerErrorCode::from_api_code))
 }
 
 /// Parses JSON body while preserving response headers for pagination metadata.
@@ -796,16 +1359,174 @@ pub struct ScrollPage<T> {
 pub struct IssueSearchParams {
     pub query: Option<String>,
     pub filter: Option<JsonMap<String, Value>>,
+    pub fields: Option<String>,
 }
 
 impl IssueSearchParams {
     /// Creates issue search params from optional query and filter map.
     pub fn new(query: Option<String>, filter: Option<JsonMap<String, Value>>) -> Self {
-        Self { query, filter }
+        Self {
+            query,
+            filter,
+            fields: None,
+        }
+    }
+
+    /// Overrides the comma-separated field projection requested from search endpoints.
+    pub fn with_fields(mut self, fields: &str) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    /// Adds a full-text condition matched by Tracker against summary and description.
+    pub fn with_fulltext(mut self, text: &str) -> Self {
+        self.filter
+            .get_or_insert_with(JsonMap::new)
+            .insert("text".to_string(), Value::String(text.trim().to_string()));
+        self
+    }
+
+    /// Restricts results to a single issue type key, e.g. `bug`.
+    pub fn with_type(mut self, issue_type: &str) -> Self {
+        self.filter
+            .get_or_insert_with(JsonMap::new)
+            .insert("type".to_string(), Value::String(issue_type.to_string()));
+        self
+    }
+
+    /// Restricts results to any of the given issue type keys.
+    pub fn with_types(mut self, types: &[&str]) -> Self {
+        let values = types
+            .iter()
+            .map(|issue_type| Value::String(issue_type.to_string()))
+            .collect();
+        self.filter
+            .get_or_insert_with(JsonMap::new)
+            .insert("type".to_string(), Value::Array(values));
+        self
+    }
+
+    /// Restricts results to issues updated at or after an RFC 3339 timestamp.
+    pub fn with_updated_since(self, date: &str) -> Result<Self> {
+        self.with_date_bound("updatedAt", DateBound::Since, date)
+    }
+
+    /// Restricts results to issues updated strictly before an RFC 3339 timestamp.
+    pub fn with_updated_before(self, date: &str) -> Result<Self> {
+        self.with_date_bound("updatedAt", DateBound::Before, date)
+    }
+
+    /// Restricts results to issues created at or after an RFC 3339 timestamp.
+    pub fn with_created_since(self, date: &str) -> Result<Self> {
+        self.with_date_bound("createdAt", DateBound::Since, date)
+    }
+
+    /// Restricts results to issues created strictly before an RFC 3339 timestamp.
+    pub fn with_created_before(self, date: &str) -> Result<Self> {
+        self.with_date_bound("createdAt", DateBound::Before, date)
+    }
+
+    /// Inserts a date bound into the filter map, merging with an existing opposite bound.
+    ///
+    /// A single bound is sent as `">= date"` / `"< date"`; once both bounds are present
+    /// for the same field they are combined into Tracker's `{"from", "to"}` range object.
+    fn with_date_bound(mut self, field: &str, bound: DateBound, date: &str) -> Result<Self> {
+        let date = date.trim();
+        chrono::DateTime::parse_from_rfc3339(date).map_err(|err| {
+            TrackerError::Other(format!(
+                "{field} bound {date:?} is not an RFC 3339 date: {err}"
+            ))
+        })?;
+
+        let filter = self.filter.get_or_insert_with(JsonMap::new);
+        let mut range = filter
+            .get(field)
+            .map(DateRange::from_value)
+            .unwrap_or_default();
+        match bound {
+            DateBound::Since => range.from = Some(date.to_string()),
+            DateBound::Before => range.to = Some(date.to_string()),
+        }
+        filter.insert(field.to_string(), range.into_value());
+        Ok(self)
+    }
+
+    /// Returns requested field projection, defaulting to the summary field set.
+    fn fields_projection(&self) -> &str {
+        self.fields.as_deref().unwrap_or(ISSUE_SUMMARY_FIELDS)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum DateBound {
+    Since,
+    Before,
+}
+
+/// Date filter bounds collected from `>= date` / `< date` strings or a range object.
+#[derive(Default)]
+struct DateRange {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+impl DateRange {
+    /// Parses a previously inserted date filter entry; unrecognized values are dropped.
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::String(text) => {
+                if let Some(date) = text.strip_prefix(">=") {
+                    Self {
+                        from: Some(date.trim().to_string()),
+                        to: None,
+                    }
+                } else if let Some(date) = text.strip_prefix('<') {
+                    Self {
+                        from: None,
+                        to: Some(date.trim().to_string()),
+                    }
+                } else {
+                    Self::default()
+                }
+            }
+            Value::Object(map) => Self {
+                from: map
+                    .get("from")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string),
+                to: map
+                    .get("to")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string),
+            },
+            _ => Self::default(),
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) => serde_json::json!({ "from": from, "to": to }),
+            (Some(from), None) => Value::String(format!(">= {from}")),
+            (None, Some(to)) => Value::String(format!("< {to}")),
+            (None, None) => Value::Null,
+        }
     }
 }
 
-const ISSUE_SUMMARY_FIELDS: &str = "key,summary,description,status,priority,type,assignee,tags,followers,spent,timeSpent";
+const ISSUE_SUMMARY_FIELDS: &str = "key,summary,description,status,priority,type,assignee,tags,components,followers,parent,deadline,votes,spent,timeSpent";
+
+/// Returns whether a raw issue field key belongs to the summary projection.
+fn is_summary_field(key: &str) -> bool {
+    ISSUE_SUMMARY_FIELDS.split(',').any(|field| field == key)
+}
+
+/// Parses Tracker timestamps, which use either RFC 3339 or a `+0000`-style offset.
+fn parse_tracker_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
 
 /// Converts dynamic worklog id into normalized string representation.
 fn worklog_id_string(value: &Value) -> Option<String> {
@@ -871,11 +1592,9 @@ pub struct ListUpdate<'a> {
 }
 
 #[derive(Debug, Serialize)]
-struct TransitionExecuteRequest<'a> {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    comment: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    resolution: Option<&'a str>,
+struct BulkChangeRequest<'a> {
+    issues: &'a [&'a str],
+    values: &'a Value,
 }
 
 #[derive(Debug, Serialize)]
@@ -943,14 +1662,16 @@ impl IssueSearchRequest {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_http_error, extract_error_code, worklog_id_string, IssueSearchParams,
-        IssueSearchRequest, ScrollType, TrackerClient,
+        build_http_error, extract_error_code, is_summary_field, redact_url, worklog_id_string,
+        IssueSearchParams, IssueSearchRequest, ScrollType, TrackerClient,
     };
     use crate::config::{AuthMethod, OrgType, TrackerConfig};
-    use crate::error::TrackerError;
+    use crate::error::{TrackerError, TrackerErrorCode};
+    use futures_util::StreamExt;
     use mockito::{Matcher, Server};
     use reqwest::StatusCode;
     use serde_json::{json, Map as JsonMap, Value};
+    use std::collections::HashMap;
 
     fn test_client(base_url: &str) -> TrackerClient {
         let config = TrackerConfig::new("test-token", OrgType::Yandex360)
@@ -964,10 +1685,134 @@ mod tests {
     }
 
     #[test]
-    fn worklog_id_string_normalizes_supported_values() {
-        assert_eq!(worklog_id_string(&Value::String(" 42 ".to_string())), Some("42".to_string()));
-        assert_eq!(worklog_id_string(&json!(101)), Some("101".to_string()));
-        assert_eq!(worklog_id_string(&Value::String("   ".to_string())), None);
+    fn new_accepts_proxy_and_rejects_malformed_proxy_url() {
+        let config = TrackerConfig::new("t", OrgType::Yandex360)
+            .with_proxy("http://proxy.example.test:3128")
+            .with_no_proxy("localhost");
+        assert!(TrackerClient::new(config).is_ok());
+
+        let config = TrackerConfig::new("t", OrgType::Yandex360).with_proxy("not a proxy url");
+        assert!(TrackerClient::new(config).is_err());
+    }
+
+    #[test]
+    fn redact_url_masks_token_query_values_only() {
+        let url = reqwest::Url::parse("https://api.test/v3/x?access_token=abc&page=2")
+            .expect("valid url");
+        let rendered = redact_url(&url);
+        assert!(!rendered.contains("abc"));
+        assert!(rendered.contains("page=2"));
+
+        let plain = reqwest::Url::parse("https://api.test/v3/myself").expect("valid url");
+        assert_eq!(redact_url(&plain), "https://api.test/v3/myself");
+    }
+
+    #[tokio::test]
+    async fn debug_requests_mode_still_parses_and_accepts_empty_responses() {
+        let mut server = Server::new_async().await;
+        let _get = server
+            .mock("GET", "/v3/issues/YT-1/comments/1")
+            .with_status(200)
+            .with_body(r#"{"id":1,"text":"hi"}"#)
+            .create_async()
+            .await;
+        let _delete = server
+            .mock("DELETE", "/v3/issues/YT-1/checklistItems")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let config = TrackerConfig::new("test-token", OrgType::Yandex360)
+            .with_base_url(server.url())
+            .with_debug_requests(true);
+        let client = TrackerClient::new(config).expect("client should be created");
+
+        let comment = client.get_comment("YT-1", "1").await.expect("comment should load");
+        assert_eq!(comment.text.as_deref(), Some("hi"));
+        client
+            .delete_checklist("YT-1")
+            .await
+            .expect("empty response should be accepted");
+    }
+
+    #[tokio::test]
+    async fn clear_scroll_contexts_attempts_every_id() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("POST", "/v3/system/search/scroll/_clear")
+            .match_body(Matcher::Json(json!({"scrollId": "sid-1"})))
+            .with_status(404)
+            .create_async()
+            .await;
+        let second = server
+            .mock("POST", "/v3/system/search/scroll/_clear")
+            .match_body(Matcher::Json(json!({"scrollId": "sid-2"})))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let result = client.clear_scroll_contexts(&["sid-1", " ", "sid-2"]).await;
+
+        assert!(result.is_err());
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn check_connectivity_records_reachability() {
+        let mut server = Server::new_async().await;
+        let _myself = server
+            .mock("GET", "/v3/myself")
+            .with_status(200)
+            .with_body(r#"{"login":"jane"}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        assert_eq!(client.last_connectivity(), None);
+        client.check_connectivity().await.expect("tracker should be reachable");
+        assert_eq!(client.last_connectivity(), Some(true));
+
+        let offline = test_client("http://127.0.0.1:1");
+        match offline.check_connectivity().await {
+            Err(TrackerError::Network(message)) => assert!(message.contains("unreachable")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert_eq!(offline.last_connectivity(), Some(false));
+    }
+
+    #[tokio::test]
+    async fn get_myself_reads_numeric_uid() {
+        let mut server = Server::new_async().await;
+        let _myself = server
+            .mock("GET", "/v3/myself")
+            .with_status(200)
+            .with_body(r#"{"uid":1234567890,"login":"jane","display":"Jane Doe"}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let profile = client.get_myself().await.expect("profile should load");
+        assert_eq!(profile.uid.as_deref(), Some("1234567890"));
+        assert_eq!(profile.login.as_deref(), Some("jane"));
+    }
+
+    #[test]
+    fn new_rejects_invalid_config() {
+        let config = TrackerConfig::new("", OrgType::Yandex360);
+        match TrackerClient::new(config) {
+            Err(TrackerError::Other(message)) => assert!(message.contains("token must not be empty")),
+            Err(other) => panic!("unexpected error variant: {other:?}"),
+            Ok(_) => panic!("client should not be created"),
+        }
+    }
+
+    #[test]
+    fn worklog_id_string_normalizes_supported_values() {
+        assert_eq!(worklog_id_string(&Value::String(" 42 ".to_string())), Some("42".to_string()));
+        assert_eq!(worklog_id_string(&json!(101)), Some("101".to_string()));
+        assert_eq!(worklog_id_string(&Value::String("   ".to_string())), None);
         assert_eq!(worklog_id_string(&Value::Bool(true)), None);
     }
 
@@ -982,10 +1827,55 @@ mod tests {
         assert_eq!(payload.filter, Some(filter));
     }
 
+    #[test]
+    fn with_fulltext_adds_trimmed_text_filter() {
+        let params = IssueSearchParams::default().with_fulltext("  login crash ");
+        assert_eq!(
+            params.filter.as_ref().expect("filter present")["text"],
+            json!("login crash")
+        );
+    }
+
+    #[test]
+    fn type_helpers_set_single_or_multiple_types() {
+        let single = IssueSearchParams::default().with_type("bug");
+        assert_eq!(single.filter.as_ref().expect("filter present")["type"], json!("bug"));
+
+        let multiple = single.with_types(&["bug", "story"]);
+        assert_eq!(
+            multiple.filter.as_ref().expect("filter present")["type"],
+            json!(["bug", "story"])
+        );
+    }
+
+    #[test]
+    fn date_bound_helpers_insert_and_merge_filter_entries() {
+        let mut filter = JsonMap::new();
+        filter.insert("queue".to_string(), json!("YT"));
+        let params = IssueSearchParams::new(None, Some(filter))
+            .with_updated_since("2024-05-01T00:00:00Z")
+            .and_then(|params| params.with_created_before("2024-06-01T00:00:00+03:00"))
+            .expect("valid dates");
+        let filter = params.filter.as_ref().expect("filter present");
+        assert_eq!(filter["queue"], json!("YT"));
+        assert_eq!(filter["updatedAt"], json!(">= 2024-05-01T00:00:00Z"));
+        assert_eq!(filter["createdAt"], json!("< 2024-06-01T00:00:00+03:00"));
+
+        let params = params
+            .with_updated_before("2024-05-31T00:00:00Z")
+            .expect("valid date");
+        assert_eq!(
+            params.filter.as_ref().expect("filter present")["updatedAt"],
+            json!({"from": "2024-05-01T00:00:00Z", "to": "2024-05-31T00:00:00Z"})
+        );
+
+        assert!(IssueSearchParams::default().with_created_since("yesterday").is_err());
+    }
+
     #[test]
     fn extract_error_code_reads_json_body_code_field() {
         let code = extract_error_code(r#"{"code":"QUEUE_NOT_FOUND","message":"no queue"}"#);
-        assert_eq!(code.as_deref(), Some("QUEUE_NOT_FOUND"));
+        assert_eq!(code, Some(TrackerErrorCode::NotFound));
         assert!(extract_error_code("not-json").is_none());
     }
 
@@ -995,7 +1885,7 @@ mod tests {
         match err {
             TrackerError::Http { status, code, .. } => {
                 assert_eq!(status, StatusCode::BAD_REQUEST);
-                assert_eq!(code.as_deref(), Some("BAD_REQ"));
+                assert_eq!(code, Some(TrackerErrorCode::Unknown("BAD_REQ".to_string())));
             }
             other => panic!("unexpected error variant: {other:?}"),
         }
@@ -1050,7 +1940,7 @@ mod tests {
         let _mock = server
             .mock("POST", "/v3/issues/_search")
             .match_query(Matcher::AllOf(vec![
-                Matcher::UrlEncoded("fields".into(), "key,summary,description,status,priority,type,assignee,tags,followers,spent,timeSpent".into()),
+                Matcher::UrlEncoded("fields".into(), "key,summary,description,status,priority,type,assignee,tags,components,followers,parent,deadline,votes,spent,timeSpent".into()),
                 Matcher::UrlEncoded("scrollType".into(), "sorted".into()),
                 Matcher::UrlEncoded("perScroll".into(), "50".into()),
                 Matcher::UrlEncoded("scrollTTLMillis".into(), "1500".into()),
@@ -1076,6 +1966,720 @@ mod tests {
         assert!(page.items.is_empty());
     }
 
+    #[tokio::test]
+    async fn prefetch_scroll_page_continues_existing_scroll() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/v3/issues/_search")
+            .match_query(Matcher::UrlEncoded("scrollId".into(), "sid-1".into()))
+            .with_status(200)
+            .with_header("X-Scroll-Id", "sid-1")
+            .with_body(r#"[{"key":"YT-101"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let page = client
+            .prefetch_scroll_page(&IssueSearchParams::default(), "sid-1", 50)
+            .await
+            .expect("prefetch should succeed");
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.scroll_id.as_deref(), Some("sid-1"));
+    }
+
+    #[tokio::test]
+    async fn search_issues_all_follows_scroll_and_releases_it() {
+        let mut server = Server::new_async().await;
+        let first_page = server
+            .mock("POST", "/v3/issues/_search")
+            .match_query(Matcher::UrlEncoded("scrollType".into(), "unsorted".into()))
+            .with_status(200)
+            .with_header("X-Scroll-Id", "sid-1")
+            .with_body(r#"[{"key":"YT-1"},{"key":"YT-2"}]"#)
+            .create_async()
+            .await;
+        let last_page = server
+            .mock("POST", "/v3/issues/_search")
+            .match_query(Matcher::UrlEncoded("scrollId".into(), "sid-1".into()))
+            .with_status(200)
+            .with_header("X-Scroll-Id", "sid-1")
+            .with_body("[]")
+            .create_async()
+            .await;
+        let clear = server
+            .mock("POST", "/v3/system/search/scroll/_clear")
+            .match_body(Matcher::Json(json!({"scrollId": "sid-1"})))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let params = IssueSearchParams::default();
+        let issues: Vec<_> = client.search_issues_all(&params).collect().await;
+        let keys: Vec<_> = issues
+            .into_iter()
+            .map(|issue| issue.expect("page should load").key)
+            .collect();
+
+        assert_eq!(keys, vec!["YT-1", "YT-2"]);
+        first_page.assert_async().await;
+        last_page.assert_async().await;
+        clear.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_issue_worklogs_in_range_keeps_entries_inside_range() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/issues/YT-1/worklog")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                r#"[{"id":1,"start":"2024-03-01T10:00:00.000+0000","duration":"PT1H"},
+                    {"id":2,"start":"2024-03-02T23:59:59Z","duration":"PT1H"},
+                    {"id":3,"start":"2024-03-03T00:00:00.000+0000","duration":"PT1H"},
+                    {"id":4,"createdAt":"2024-03-02T08:00:00.000+0000","duration":"PT1H"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let from = "2024-03-01T00:00:00Z".parse().expect("valid from");
+        let to = "2024-03-03T00:00:00Z".parse().expect("valid to");
+        let entries = client
+            .get_issue_worklogs_in_range("YT-1", from, to)
+            .await
+            .expect("worklogs should load");
+
+        let ids: Vec<Value> = entries.into_iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![json!(1), json!(2), json!(4)]);
+    }
+
+    #[tokio::test]
+    async fn get_issue_count_reads_total_from_single_item_page() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/v3/issues/_search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("perPage".into(), "1".into()),
+                Matcher::UrlEncoded("fields".into(), "key".into()),
+            ]))
+            .match_body(Matcher::Json(json!({"filter": {"type": "bug"}})))
+            .with_status(200)
+            .with_header("X-Total-Count", "42")
+            .with_body(r#"[{"key":"YT-1"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let params = IssueSearchParams::default().with_type("bug");
+        let count = client
+            .get_issue_count(&params)
+            .await
+            .expect("count should load");
+
+        assert_eq!(count, 42);
+    }
+
+    #[test]
+    fn is_summary_field_matches_projection_keys_only() {
+        assert!(is_summary_field("key"));
+        assert!(is_summary_field("timeSpent"));
+        assert!(!is_summary_field("epic"));
+        assert!(!is_summary_field("time"));
+    }
+
+    #[tokio::test]
+    async fn get_issue_custom_fields_drops_summary_fields() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/issues/YT-1")
+            .with_status(200)
+            .with_body(r#"{"key":"YT-1","summary":"Title","epic":{"key":"YT-0"},"storyPoints":3}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let fields = client
+            .get_issue_custom_fields("YT-1")
+            .await
+            .expect("custom fields should load");
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields.get("storyPoints"), Some(&json!(3)));
+        assert_eq!(fields.get("epic"), Some(&json!({"key": "YT-0"})));
+    }
+
+    #[tokio::test]
+    async fn get_issue_with_fields_sends_requested_projection() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v3/issues/YT-1")
+            .match_query(Matcher::UrlEncoded("fields".into(), "key,summary,sprint".into()))
+            .with_status(200)
+            .with_body(r#"{"key":"YT-1","summary":"Title"}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let issue = client
+            .get_issue_with_fields("YT-1", "key,summary,sprint")
+            .await
+            .expect("issue should load");
+
+        mock.assert_async().await;
+        assert_eq!(issue.key, "YT-1");
+    }
+
+    #[tokio::test]
+    async fn list_all_issue_types_filters_by_queue() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v3/issuetypes")
+            .match_query(Matcher::UrlEncoded("queue".into(), "OPS".into()))
+            .with_status(200)
+            .with_body(r#"[{"key":"bug","display":"Bug"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let types = client
+            .list_all_issue_types(Some("OPS"))
+            .await
+            .expect("issue types should load");
+
+        mock.assert_async().await;
+        assert_eq!(types.len(), 1);
+        assert_eq!(types[0].key.as_deref(), Some("bug"));
+    }
+
+    #[tokio::test]
+    async fn get_queue_issue_types_lists_queue_types() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v3/queues/OPS/issuetypes")
+            .with_status(200)
+            .with_body(r#"[{"key":"task","display":"Task"},{"key":"bug","display":"Bug"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let types = client
+            .get_queue_issue_types("OPS")
+            .await
+            .expect("queue issue types should load");
+
+        mock.assert_async().await;
+        let keys: Vec<_> = types.iter().filter_map(|entry| entry.key.as_deref()).collect();
+        assert_eq!(keys, vec!["task", "bug"]);
+    }
+
+    #[tokio::test]
+    async fn get_queue_reads_lead_and_default_type() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v3/queues/OPS")
+            .with_status(200)
+            .with_body(
+                r#"{"id":3,"key":"OPS","name":"Operations","description":"On-call work",
+                "lead":{"id":"42","display":"Jane Doe"},
+                "defaultType":{"id":"2","key":"task","display":"Task"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let queue = client.get_queue("OPS").await.expect("queue should load");
+
+        mock.assert_async().await;
+        assert_eq!(queue.id.as_deref(), Some("3"));
+        assert_eq!(queue.name.as_deref(), Some("Operations"));
+        assert_eq!(queue.description.as_deref(), Some("On-call work"));
+        assert_eq!(
+            queue.lead.and_then(|lead| lead.display).as_deref(),
+            Some("Jane Doe")
+        );
+        assert_eq!(
+            queue.default_type.and_then(|entry| entry.key).as_deref(),
+            Some("task")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_queue_components_lists_queue_components() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/queues/OPS/components")
+            .with_status(200)
+            .with_body(r#"[{"id":7,"name":"Backend","display":"Backend"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let components = client
+            .get_queue_components("OPS")
+            .await
+            .expect("components should load");
+
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].id.as_deref(), Some("7"));
+    }
+
+    #[tokio::test]
+    async fn move_issue_to_queue_patches_queue_key() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PATCH", "/v3/issues/YT-1")
+            .match_body(Matcher::Json(json!({"queue": {"key": "OPS"}})))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        client
+            .move_issue_to_queue("YT-1", "OPS")
+            .await
+            .expect("issue should be moved");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn issue_tags_are_read_from_projection_and_replaced_whole() {
+        let mut server = Server::new_async().await;
+        let get_mock = server
+            .mock("GET", "/v3/issues/YT-1")
+            .match_query(Matcher::UrlEncoded("fields".into(), "tags".into()))
+            .with_status(200)
+            .with_body(r#"{"key":"YT-1","tags":["backend","urgent"]}"#)
+            .create_async()
+            .await;
+        let patch_mock = server
+            .mock("PATCH", "/v3/issues/YT-1")
+            .match_body(Matcher::Json(json!({"tags": ["backend"]})))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let tags = client
+            .get_issue_tags("YT-1")
+            .await
+            .expect("tags should load");
+        assert_eq!(tags, vec!["backend", "urgent"]);
+        client
+            .set_issue_tags("YT-1", &["backend"])
+            .await
+            .expect("tags should be replaced");
+
+        get_mock.assert_async().await;
+        patch_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn issue_votes_are_added_and_withdrawn() {
+        let mut server = Server::new_async().await;
+        let vote_mock = server
+            .mock("POST", "/v3/issues/YT-1/votes")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+        let unvote_mock = server
+            .mock("DELETE", "/v3/issues/YT-1/votes")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        client
+            .vote_for_issue("YT-1")
+            .await
+            .expect("vote should be added");
+        client
+            .unvote_issue("YT-1")
+            .await
+            .expect("vote should be withdrawn");
+
+        vote_mock.assert_async().await;
+        unvote_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn issue_subscription_is_added_and_removed() {
+        let mut server = Server::new_async().await;
+        let subscribe_mock = server
+            .mock("POST", "/v3/issues/YT-1/subscribers")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+        let unsubscribe_mock = server
+            .mock("DELETE", "/v3/issues/YT-1/subscribers")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        client
+            .subscribe_to_issue("YT-1")
+            .await
+            .expect("subscription should be added");
+        client
+            .unsubscribe_from_issue("YT-1")
+            .await
+            .expect("subscription should be removed");
+
+        subscribe_mock.assert_async().await;
+        unsubscribe_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn bulk_update_issue_fields_posts_keys_and_patch() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v3/issues/_bulkchange")
+            .match_body(Matcher::Json(json!({
+                "issues": ["YT-1", "YT-2"],
+                "values": {"priority": "critical"}
+            })))
+            .with_status(201)
+            .with_body(r#"{"id":"op-1","status":"CREATED"}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let response = client
+            .bulk_update_issue_fields(&["YT-1", "YT-2"], &json!({"priority": "critical"}))
+            .await
+            .expect("bulk change should be accepted");
+        assert_eq!(response.operation_id, "op-1");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_transitions_with_fields_reads_screen_fields() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/issues/YT-1/transitions")
+            .with_status(200)
+            .with_body(
+                r#"[{"id":"close","display":"Close","to":{"key":"closed"},
+                     "screen":{"fields":[{"id":"resolution","name":"Resolution",
+                                          "schema":{"type":"resolution","required":true}}]}},
+                    {"id":"start","display":"Start","to":{"key":"inProgress"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let transitions = client
+            .get_transitions_with_fields("YT-1")
+            .await
+            .expect("transitions should load");
+
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].transition.id.as_deref(), Some("close"));
+        assert_eq!(transitions[0].required_fields.len(), 1);
+        assert_eq!(transitions[0].required_fields[0].id, "resolution");
+        assert!(transitions[0].required_fields[0].required);
+        assert!(transitions[1].required_fields.is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_transition_merges_convenience_params_into_fields() {
+        let mut server = Server::new_async().await;
+        let with_fields = server
+            .mock("POST", "/v3/issues/YT-1/transitions/close/_execute")
+            .match_body(Matcher::Json(json!({"resolution": "fixed", "spent": "PT1H"})))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        let convenience = server
+            .mock("POST", "/v3/issues/YT-2/transitions/close/_execute")
+            .match_body(Matcher::Json(json!({"comment": "Done", "resolution": "fixed"})))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let fields = HashMap::from([
+            ("resolution".to_string(), json!("fixed")),
+            ("spent".to_string(), json!("PT1H")),
+        ]);
+        client
+            .execute_transition_with_fields("YT-1", "close", &fields)
+            .await
+            .expect("transition with fields should execute");
+        client
+            .execute_transition("YT-2", "close", Some("Done"), Some("fixed"))
+            .await
+            .expect("transition should execute");
+
+        with_fields.assert_async().await;
+        convenience.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn post_with_timeout_fails_when_response_is_slower_than_override() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/v3/slow")
+            .with_status(200)
+            .with_chunked_body(|writer| {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                writer.write_all(b"{}")
+            })
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let result: crate::Result<Value> = client
+            .post_with_timeout("slow", &json!({}), std::time::Duration::from_millis(50))
+            .await;
+        assert!(matches!(result, Err(TrackerError::Timeout(_))), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn update_issue_custom_field_patches_single_key() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("PATCH", "/v3/issues/YT-1")
+            .match_body(Matcher::Json(json!({"storyPoints": 5})))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        client
+            .update_issue_custom_field("YT-1", "storyPoints", json!(5))
+            .await
+            .expect("patch should succeed");
+    }
+
+    #[tokio::test]
+    async fn get_queue_fields_normalizes_schema_and_allowed_values() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/queues/YT/fields")
+            .with_status(200)
+            .with_body(
+                r#"[
+                    {"id":"summary","name":"Summary","schema":{"type":"string","required":true}},
+                    {"id":"stand","name":"Stand","schema":{"type":"string"},
+                     "optionsProvider":{"values":["dev","prod",{"key":"qa","display":"QA"}]}}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let fields = client
+            .get_queue_fields("YT")
+            .await
+            .expect("queue fields should load");
+
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].id, "summary");
+        assert_eq!(fields[0].type_id.as_deref(), Some("string"));
+        assert!(fields[0].required);
+        assert!(fields[0].allowed_values.is_empty());
+        assert!(!fields[1].required);
+        let keys: Vec<_> = fields[1]
+            .allowed_values
+            .iter()
+            .filter_map(|value| value.key.as_deref())
+            .collect();
+        assert_eq!(keys, vec!["dev", "prod", "qa"]);
+    }
+
+    #[tokio::test]
+    async fn search_issues_uses_custom_field_projection() {
+        let mut server = Server::new_async().await;
+        let _search = server
+            .mock("POST", "/v3/issues/_search")
+            .match_query(Matcher::UrlEncoded("fields".into(), "key,summary".into()))
+            .with_status(200)
+            .with_body(r#"[{"key":"YT-1","summary":"Only summary"}]"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let params = IssueSearchParams::default().with_fields("key,summary");
+        let issues = client
+            .search_issues(&params, Some(10))
+            .await
+            .expect("search should succeed");
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].key, "YT-1");
+    }
+
+    #[tokio::test]
+    async fn get_issue_linked_issues_fetches_each_linked_issue() {
+        let mut server = Server::new_async().await;
+        let _links = server
+            .mock("GET", "/v3/issues/YT-1/links")
+            .with_status(200)
+            .with_body(
+                r#"[{"id":1,"type":{"id":"depends","inward":"is dependent by","outward":"depends on"},
+                     "direction":"inward","object":{"key":"YT-2","display":"Dependency"}},
+                    {"id":2,"type":{"id":"relates"},"object":{"display":"no key"}}]"#,
+            )
+            .create_async()
+            .await;
+        let _linked = server
+            .mock("GET", "/v3/issues/YT-2")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(r#"{"key":"YT-2","summary":"Dependency","status":{"key":"open","display":"Open"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let linked = client
+            .get_issue_linked_issues("YT-1")
+            .await
+            .expect("linked issues should load");
+
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].relation_type, "depends");
+        assert_eq!(linked[0].direction, "inward");
+        assert_eq!(linked[0].issue.key, "YT-2");
+        assert_eq!(linked[0].issue.summary.as_deref(), Some("Dependency"));
+    }
+
+    #[tokio::test]
+    async fn get_issue_changelog_parses_field_changes_and_null_payload() {
+        let mut server = Server::new_async().await;
+        let _history = server
+            .mock("GET", "/v3/issues/YT-1/changelog")
+            .match_query(Matcher::UrlEncoded("perPage".into(), "100".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"id":"c1","updatedAt":"2024-05-01T10:00:00.000+0000","type":"IssueWorkflow",
+                     "updatedBy":{"display":"Jane"},
+                     "fields":[{"field":{"id":"status","display":"Status"},"from":{"key":"open"},"to":{"key":"inProgress"}}]}]"#,
+            )
+            .create_async()
+            .await;
+        let _empty = server
+            .mock("GET", "/v3/issues/YT-2/changelog")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body("null")
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let entries = client
+            .get_issue_changelog("YT-1")
+            .await
+            .expect("changelog should load");
+        assert_eq!(entries.len(), 1);
+        let fields = entries[0].fields.as_ref().expect("fields present");
+        assert_eq!(fields[0].field.as_ref().and_then(|f| f.id.as_deref()), Some("status"));
+        assert_eq!(fields[0].to, Some(json!({"key": "inProgress"})));
+
+        let empty = client
+            .get_issue_changelog("YT-2")
+            .await
+            .expect("null changelog should be empty");
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_comment_fetches_single_comment() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/v3/issues/YT-1/comments/42")
+            .with_status(200)
+            .with_body(r#"{"id":42,"text":"Looks good","createdBy":{"display":"Jane"}}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let comment = client
+            .get_comment("YT-1", "42")
+            .await
+            .expect("comment should load");
+        assert_eq!(comment.id, json!(42));
+        assert_eq!(comment.text.as_deref(), Some("Looks good"));
+    }
+
+    #[tokio::test]
+    async fn comment_reactions_use_percent_encoded_emoji_path() {
+        let mut server = Server::new_async().await;
+        let add = server
+            .mock("POST", "/v3/issues/YT-1/comments/42/reactions/%F0%9F%91%8D")
+            .with_status(204)
+            .create_async()
+            .await;
+        let remove = server
+            .mock("DELETE", "/v3/issues/YT-1/comments/42/reactions/%F0%9F%91%8D")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        client
+            .add_comment_reaction("YT-1", "42", "\u{1F44D}")
+            .await
+            .expect("reaction should be added");
+        client
+            .remove_comment_reaction("YT-1", "42", "\u{1F44D}")
+            .await
+            .expect("reaction should be removed");
+
+        add.assert_async().await;
+        remove.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn clone_issue_copies_core_fields_into_source_queue() {
+        let mut server = Server::new_async().await;
+        let _source = server
+            .mock("GET", "/v3/issues/YT-7")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                r#"{"key":"YT-7","summary":"Original","description":"Details",
+                    "type":{"key":"bug","display":"Bug"},"priority":{"key":"critical"},
+                    "assignee":{"id":"jane"},"followers":[{"id":"john"}]}"#,
+            )
+            .create_async()
+            .await;
+        let create = server
+            .mock("POST", "/v3/issues")
+            .match_body(Matcher::Json(json!({
+                "queue": "YT",
+                "summary": "Copy",
+                "description": "Details",
+                "type": "bug",
+                "priority": "critical"
+            })))
+            .with_status(201)
+            .with_body(r#"{"key":"YT-8","summary":"Copy"}"#)
+            .create_async()
+            .await;
+
+        let client = test_client(&server.url());
+        let created = client
+            .clone_issue("YT-7", Some("Copy"))
+            .await
+            .expect("issue should be cloned");
+
+        assert_eq!(created.key, "YT-8");
+        create.assert_async().await;
+    }
+
     #[tokio::test]
     async fn fetch_binary_supports_relative_href_and_content_type() {
         let mut server = Server::new_async().await;
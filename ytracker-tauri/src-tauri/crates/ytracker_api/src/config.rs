@@ -1,13 +1,27 @@
 //! Configuration primitives for Tracker API base URL, headers, and timeouts.
 
+use std::env;
+use std::fmt;
 use std::time::Duration;
 
+use reqwest::Url;
+
+use crate::error::{Result, TrackerError};
+
 pub const DEFAULT_API_BASE: &str = "https://api.tracker.yandex.net";
 pub const DEFAULT_API_VERSION: &str = "v3";
 pub const DEFAULT_USER_AGENT: &str = "ytracker-tauri";
 pub const DEFAULT_COOLDOWN_MS: u64 = 500;
 pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
 pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+pub const DEFAULT_UPLOAD_TIMEOUT_SECS: u64 = 120;
+
+pub const ENV_TOKEN: &str = "YTRACKER_TOKEN";
+pub const ENV_ORG_TYPE: &str = "YTRACKER_ORG_TYPE";
+pub const ENV_ORG_ID: &str = "YTRACKER_ORG_ID";
+pub const ENV_BASE_URL: &str = "YTRACKER_BASE_URL";
+pub const ENV_COOLDOWN_MS: &str = "YTRACKER_COOLDOWN_MS";
+pub const ENV_TIMEOUT_SECS: &str = "YTRACKER_TIMEOUT_SECS";
 
 /// Represents organization types supported by Tracker API, which require different header names for org id.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -25,6 +39,14 @@ impl OrgType {
         }
     }
 }
+/// Canonicalizes external org type input, falling back to Yandex 360 for unknown values.
+pub fn normalize_org_type(value: &str) -> OrgType {
+    match value.trim().to_lowercase().as_str() {
+        "cloud" => OrgType::Cloud,
+        _ => OrgType::Yandex360,
+    }
+}
+
 /// Represents authorization scheme used for API requests.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AuthMethod {
@@ -42,7 +64,7 @@ impl AuthMethod {
     }
 }
 /// Represents configuration parameters for Tracker API client, including base URL, auth token, headers and timeouts.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct TrackerConfig {
     pub base_url: String,
     pub api_version: String,
@@ -54,7 +76,34 @@ pub struct TrackerConfig {
     pub cooldown: Duration,
     pub timeout: Duration,
     pub connect_timeout: Duration,
+    pub upload_timeout: Duration,
     pub auth_method: AuthMethod,
+    pub proxy_url: Option<String>,
+    pub no_proxy: Option<String>,
+    pub debug_requests: bool,
+}
+
+impl fmt::Debug for TrackerConfig {
+    /// Formats config fields with the auth token redacted.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrackerConfig")
+            .field("base_url", &self.base_url)
+            .field("api_version", &self.api_version)
+            .field("token", &"<redacted>")
+            .field("org_id", &self.org_id)
+            .field("org_type", &self.org_type)
+            .field("accept_language", &self.accept_language)
+            .field("user_agent", &self.user_agent)
+            .field("cooldown", &self.cooldown)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("upload_timeout", &self.upload_timeout)
+            .field("auth_method", &self.auth_method)
+            .field("proxy_url", &self.proxy_url)
+            .field("no_proxy", &self.no_proxy)
+            .field("debug_requests", &self.debug_requests)
+            .finish()
+    }
 }
 
 impl TrackerConfig {
@@ -71,8 +120,58 @@ impl TrackerConfig {
             cooldown: Duration::from_millis(DEFAULT_COOLDOWN_MS),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
+            upload_timeout: Duration::from_secs(DEFAULT_UPLOAD_TIMEOUT_SECS),
             auth_method: AuthMethod::OAuth,
+            proxy_url: None,
+            no_proxy: None,
+            debug_requests: false,
+        }
+    }
+
+    /// Builds config from `YTRACKER_*` environment variables, requiring only the token.
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let read = |key: &str| {
+            lookup(key)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let parse_u64 = |key: &str| -> Result<Option<u64>> {
+            read(key)
+                .map(|value| {
+                    value.parse::<u64>().map_err(|_| {
+                        TrackerError::Other(format!(
+                            "{key} must be a non-negative integer, got {value:?}"
+                        ))
+                    })
+                })
+                .transpose()
+        };
+
+        let token = read(ENV_TOKEN).ok_or_else(|| {
+            TrackerError::Other(format!("{ENV_TOKEN} environment variable is not set"))
+        })?;
+        let org_type = read(ENV_ORG_TYPE)
+            .map(|value| normalize_org_type(&value))
+            .unwrap_or(OrgType::Yandex360);
+
+        let mut config = Self::new(token, org_type);
+        if let Some(org_id) = read(ENV_ORG_ID) {
+            config = config.with_org_id(org_id);
+        }
+        if let Some(base_url) = read(ENV_BASE_URL) {
+            config = config.with_base_url(base_url);
+        }
+        if let Some(cooldown_ms) = parse_u64(ENV_COOLDOWN_MS)? {
+            config = config.with_cooldown(Duration::from_millis(cooldown_ms));
+        }
+        if let Some(timeout_secs) = parse_u64(ENV_TIMEOUT_SECS)? {
+            config = config.with_timeout(Duration::from_secs(timeout_secs));
         }
+        Ok(config)
     }
 
     /// Sets organization id header value.
@@ -123,12 +222,87 @@ impl TrackerConfig {
         self
     }
 
+    /// Sets total timeout for attachment uploads and binary downloads.
+    pub fn with_upload_timeout(mut self, duration: Duration) -> Self {
+        self.upload_timeout = duration;
+        self
+    }
+
     /// Selects authorization scheme used for API requests.
     pub fn with_auth_method(mut self, method: AuthMethod) -> Self {
         self.auth_method = method;
         self
     }
 
+    /// Routes all requests through an explicit HTTP(S) proxy instead of the system default.
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy_url = Some(url.into());
+        self
+    }
+
+    /// Sets comma-separated hosts that bypass the configured proxy.
+    pub fn with_no_proxy(mut self, hosts: impl Into<String>) -> Self {
+        self.no_proxy = Some(hosts.into());
+        self
+    }
+
+    /// Enables `debug!` logging of HTTP exchanges, including success bodies up to 4 KB.
+    pub fn with_debug_requests(mut self, enabled: bool) -> Self {
+        self.debug_requests = enabled;
+        self
+    }
+
+    /// Checks config invariants and returns every violation found.
+    ///
+    /// Plain `http` is accepted only for loopback hosts so local mock servers keep working.
+    pub fn validate(&self) -> std::result::Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        if self.token.trim().is_empty() {
+            errors.push("token must not be empty".to_string());
+        }
+
+        match Url::parse(&self.base_url) {
+            Ok(url) => {
+                let loopback = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+                if url.scheme() != "https" && !(url.scheme() == "http" && loopback) {
+                    errors.push(format!(
+                        "base_url must use https scheme, got {:?}",
+                        self.base_url
+                    ));
+                }
+            }
+            Err(err) => errors.push(format!(
+                "base_url {:?} is not a valid URL: {err}",
+                self.base_url
+            )),
+        }
+
+        if !is_valid_api_version(&self.api_version) {
+            errors.push(format!(
+                "api_version must look like v<number>, got {:?}",
+                self.api_version
+            ));
+        }
+
+        if self.cooldown.is_zero() {
+            errors.push("cooldown must be greater than zero".to_string());
+        }
+
+        if self.timeout <= self.connect_timeout {
+            errors.push(format!(
+                "timeout ({:?}) must be greater than connect_timeout ({:?})",
+                self.timeout, self.connect_timeout
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Returns canonical API root URL ending with a trailing slash.
     pub fn api_root(&self) -> String {
         format!(
@@ -139,11 +313,29 @@ impl TrackerConfig {
     }
 }
 
+/// Returns whether API version path segment matches `v<digits>`.
+fn is_valid_api_version(version: &str) -> bool {
+    version
+        .trim_matches('/')
+        .strip_prefix('v')
+        .is_some_and(|digits| !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{AuthMethod, OrgType, TrackerConfig};
+    use super::{normalize_org_type, AuthMethod, OrgType, TrackerConfig};
+    use crate::error::TrackerError;
+    use std::collections::HashMap;
     use std::time::Duration;
 
+    fn lookup_from(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
     #[test]
     fn org_type_header_names_match_contract() {
         assert_eq!(OrgType::Yandex360.header_name(), "X-Org-ID");
@@ -167,7 +359,11 @@ mod tests {
             .with_cooldown(Duration::from_millis(50))
             .with_timeout(Duration::from_secs(5))
             .with_connect_timeout(Duration::from_secs(3))
-            .with_auth_method(AuthMethod::Bearer);
+            .with_upload_timeout(Duration::from_secs(300))
+            .with_auth_method(AuthMethod::Bearer)
+            .with_proxy("http://proxy.example.test:3128")
+            .with_no_proxy("localhost,.internal")
+            .with_debug_requests(true);
 
         assert_eq!(config.token, "token-1");
         assert_eq!(config.org_id.as_deref(), Some("org-77"));
@@ -176,7 +372,98 @@ mod tests {
         assert_eq!(config.cooldown, Duration::from_millis(50));
         assert_eq!(config.timeout, Duration::from_secs(5));
         assert_eq!(config.connect_timeout, Duration::from_secs(3));
+        assert_eq!(config.upload_timeout, Duration::from_secs(300));
+        assert_eq!(config.proxy_url.as_deref(), Some("http://proxy.example.test:3128"));
+        assert_eq!(config.no_proxy.as_deref(), Some("localhost,.internal"));
+        assert!(config.debug_requests);
         assert_eq!(config.auth_method, AuthMethod::Bearer);
         assert_eq!(config.api_root(), "https://example.test/v9/");
     }
+
+    #[test]
+    fn validate_accepts_defaults_and_loopback_http() {
+        assert!(TrackerConfig::new("t", OrgType::Yandex360)
+            .validate()
+            .is_ok());
+        assert!(TrackerConfig::new("t", OrgType::Yandex360)
+            .with_base_url("http://127.0.0.1:1234")
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_collects_all_violations() {
+        let errors = TrackerConfig::new("  ", OrgType::Cloud)
+            .with_base_url("http://tracker.example.test")
+            .with_api_version("latest")
+            .with_cooldown(Duration::ZERO)
+            .with_timeout(Duration::from_secs(5))
+            .with_connect_timeout(Duration::from_secs(5))
+            .validate()
+            .expect_err("config should be invalid");
+
+        assert_eq!(errors.len(), 5, "{errors:?}");
+        assert!(TrackerConfig::new("t", OrgType::Cloud)
+            .with_base_url("not a url")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn debug_output_redacts_token() {
+        let config = TrackerConfig::new("secret-token-value", OrgType::Cloud).with_org_id("org-5");
+        let rendered = format!("{config:?}");
+
+        assert!(!rendered.contains("secret-token-value"));
+        assert!(rendered.contains("token: \"<redacted>\""));
+        assert!(rendered.contains("org-5"));
+    }
+
+    #[test]
+    fn normalize_org_type_accepts_cloud_and_defaults_to_yandex360() {
+        assert_eq!(normalize_org_type(" Cloud "), OrgType::Cloud);
+        assert_eq!(normalize_org_type("yandex360"), OrgType::Yandex360);
+        assert_eq!(normalize_org_type("other"), OrgType::Yandex360);
+    }
+
+    #[test]
+    fn from_lookup_reads_all_supported_variables() {
+        let config = TrackerConfig::from_lookup(lookup_from(&[
+            ("YTRACKER_TOKEN", "env-token"),
+            ("YTRACKER_ORG_TYPE", "cloud"),
+            ("YTRACKER_ORG_ID", "org-1"),
+            ("YTRACKER_BASE_URL", "https://example.test"),
+            ("YTRACKER_COOLDOWN_MS", "250"),
+            ("YTRACKER_TIMEOUT_SECS", "12"),
+        ]))
+        .expect("config");
+
+        assert_eq!(config.token, "env-token");
+        assert_eq!(config.org_type, OrgType::Cloud);
+        assert_eq!(config.org_id.as_deref(), Some("org-1"));
+        assert_eq!(config.base_url, "https://example.test");
+        assert_eq!(config.cooldown, Duration::from_millis(250));
+        assert_eq!(config.timeout, Duration::from_secs(12));
+    }
+
+    #[test]
+    fn from_lookup_applies_defaults_and_requires_token() {
+        let config =
+            TrackerConfig::from_lookup(lookup_from(&[("YTRACKER_TOKEN", "t")])).expect("config");
+        assert_eq!(config.org_type, OrgType::Yandex360);
+        assert!(config.org_id.is_none());
+        assert_eq!(config.base_url, super::DEFAULT_API_BASE);
+        assert_eq!(config.upload_timeout, Duration::from_secs(super::DEFAULT_UPLOAD_TIMEOUT_SECS));
+        assert!(!config.debug_requests);
+
+        match TrackerConfig::from_lookup(lookup_from(&[])) {
+            Err(TrackerError::Other(message)) => assert!(message.contains("YTRACKER_TOKEN")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert!(TrackerConfig::from_lookup(lookup_from(&[
+            ("YTRACKER_TOKEN", "t"),
+            ("YTRACKER_TIMEOUT_SECS", "soon"),
+        ]))
+        .is_err());
+    }
 }
@@ -1,6 +1,8 @@
 //! Error model used by Tracker API client operations.
 
+use std::fmt;
 use std::io;
+use std::time::Duration;
 
 use reqwest::StatusCode;
 use thiserror::Error;
@@ -13,7 +15,7 @@ pub enum TrackerError {
     #[error("http {status}: {message}")]
     Http {
         status: StatusCode,
-        code: Option<String>,
+        code: Option<TrackerErrorCode>,
         message: String,
     },
     #[error("authentication error: {0}")]
@@ -30,15 +32,106 @@ pub enum TrackerError {
     Other(String),
 }
 
+/// Known API error codes reported in the `code` field of Tracker error bodies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackerErrorCode {
+    NotFound,
+    AlreadyExists,
+    InvalidField,
+    QuotaExceeded,
+    AccessDenied,
+    Unknown(String),
+}
+
+impl TrackerErrorCode {
+    /// Maps a raw API code string onto a known variant, keeping unrecognized codes verbatim.
+    ///
+    /// Tracker prefixes many codes with the entity name (`QUEUE_NOT_FOUND`,
+    /// `ISSUE_ALREADY_EXISTS`), so matching is done on the suffix.
+    pub fn from_api_code(s: &str) -> TrackerErrorCode {
+        let normalized = s.trim().to_ascii_uppercase().replace(['-', '.', ' '], "_");
+        if normalized.ends_with("NOT_FOUND") {
+            TrackerErrorCode::NotFound
+        } else if normalized.ends_with("ALREADY_EXISTS") || normalized == "CONFLICT" {
+            TrackerErrorCode::AlreadyExists
+        } else if normalized.starts_with("INVALID_FIELD")
+            || normalized.ends_with("INVALID_FIELD")
+            || normalized.ends_with("FIELD_VALIDATION_FAILED")
+        {
+            TrackerErrorCode::InvalidField
+        } else if normalized.ends_with("QUOTA_EXCEEDED") || normalized == "TOO_MANY_REQUESTS" {
+            TrackerErrorCode::QuotaExceeded
+        } else if normalized.ends_with("ACCESS_DENIED")
+            || normalized.ends_with("FORBIDDEN")
+            || normalized.ends_with("PERMISSION_DENIED")
+        {
+            TrackerErrorCode::AccessDenied
+        } else {
+            TrackerErrorCode::Unknown(s.to_string())
+        }
+    }
+}
+
+impl fmt::Display for TrackerErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackerErrorCode::NotFound => f.write_str("NOT_FOUND"),
+            TrackerErrorCode::AlreadyExists => f.write_str("ALREADY_EXISTS"),
+            TrackerErrorCode::InvalidField => f.write_str("INVALID_FIELD"),
+            TrackerErrorCode::QuotaExceeded => f.write_str("QUOTA_EXCEEDED"),
+            TrackerErrorCode::AccessDenied => f.write_str("ACCESS_DENIED"),
+            TrackerErrorCode::Unknown(code) => f.write_str(code),
+        }
+    }
+}
+
 impl TrackerError {
     /// Constructs an HTTP error variant with optional API-specific code.
-    pub fn http(status: StatusCode, code: Option<String>, message: impl Into<String>) -> Self {
+    pub fn http(
+        status: StatusCode,
+        code: Option<TrackerErrorCode>,
+        message: impl Into<String>,
+    ) -> Self {
         TrackerError::Http {
             status,
             code,
             message: message.into(),
         }
     }
+
+    /// Returns true when the failure is transient and the request may succeed on retry.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            TrackerError::Network(_) | TrackerError::Timeout(_) => true,
+            TrackerError::Http { status, .. } => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            _ => false,
+        }
+    }
+
+    /// Extracts a `Retry-After: <seconds>` hint from HTTP 429 error messages.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            TrackerError::Http {
+                status, message, ..
+            } if *status == StatusCode::TOO_MANY_REQUESTS => parse_retry_after_hint(message),
+            _ => None,
+        }
+    }
+}
+
+fn parse_retry_after_hint(message: &str) -> Option<Duration> {
+    const HINT: &str = "retry-after";
+    let lowered = message.to_ascii_lowercase();
+    let start = lowered.find(HINT)? + HINT.len();
+    let rest = lowered[start..].trim_start_matches([':', '=', ' ']);
+    let digits: String = rest.chars().take_while(|ch| ch.is_ascii_digit()).collect();
+    digits.parse::<u64>().ok().map(Duration::from_secs)
 }
 
 impl From<reqwest::Error> for TrackerError {
@@ -70,14 +163,15 @@ impl From<serde_json::Error> for TrackerError {
 
 #[cfg(test)]
 mod tests {
-    use super::TrackerError;
+    use super::{TrackerError, TrackerErrorCode};
     use reqwest::StatusCode;
+    use std::time::Duration;
 
     #[test]
     fn http_constructor_sets_status_code_and_message() {
         let err = TrackerError::http(
             StatusCode::BAD_REQUEST,
-            Some("BAD_INPUT".to_string()),
+            Some(TrackerErrorCode::InvalidField),
             "invalid payload",
         );
 
@@ -88,13 +182,41 @@ mod tests {
                 message,
             } => {
                 assert_eq!(status, StatusCode::BAD_REQUEST);
-                assert_eq!(code.as_deref(), Some("BAD_INPUT"));
+                assert_eq!(code, Some(TrackerErrorCode::InvalidField));
                 assert_eq!(message, "invalid payload");
             }
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
 
+    #[test]
+    fn from_api_code_maps_known_suffixes_and_keeps_unknown_codes() {
+        assert_eq!(
+            TrackerErrorCode::from_api_code("QUEUE_NOT_FOUND"),
+            TrackerErrorCode::NotFound
+        );
+        assert_eq!(
+            TrackerErrorCode::from_api_code("issue_already_exists"),
+            TrackerErrorCode::AlreadyExists
+        );
+        assert_eq!(
+            TrackerErrorCode::from_api_code("INVALID_FIELD"),
+            TrackerErrorCode::InvalidField
+        );
+        assert_eq!(
+            TrackerErrorCode::from_api_code("QUOTA_EXCEEDED"),
+            TrackerErrorCode::QuotaExceeded
+        );
+        assert_eq!(
+            TrackerErrorCode::from_api_code("ACCESS_DENIED"),
+            TrackerErrorCode::AccessDenied
+        );
+        assert_eq!(
+            TrackerErrorCode::from_api_code("BAD_INPUT"),
+            TrackerErrorCode::Unknown("BAD_INPUT".to_string())
+        );
+    }
+
     #[test]
     fn serde_json_error_maps_to_serialization_variant() {
         let parse_err = serde_json::from_str::<serde_json::Value>("not-json").unwrap_err();
@@ -107,4 +229,40 @@ mod tests {
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
+
+    #[test]
+    fn is_retryable_covers_transient_failures_only() {
+        assert!(TrackerError::Network("reset".into()).is_retryable());
+        assert!(TrackerError::Timeout("slow".into()).is_retryable());
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::GATEWAY_TIMEOUT,
+        ] {
+            assert!(TrackerError::http(status, None, "").is_retryable());
+        }
+
+        assert!(!TrackerError::http(StatusCode::NOT_FOUND, None, "").is_retryable());
+        assert!(!TrackerError::http(StatusCode::INTERNAL_SERVER_ERROR, None, "").is_retryable());
+        assert!(!TrackerError::Authentication("denied".into()).is_retryable());
+        assert!(!TrackerError::Other("boom".into()).is_retryable());
+    }
+
+    #[test]
+    fn retry_after_parses_seconds_from_rate_limit_message() {
+        let err = TrackerError::http(
+            StatusCode::TOO_MANY_REQUESTS,
+            None,
+            "too many requests; Retry-After: 12",
+        );
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(12)));
+
+        let without_hint = TrackerError::http(StatusCode::TOO_MANY_REQUESTS, None, "slow down");
+        assert_eq!(without_hint.retry_after(), None);
+
+        let other_status =
+            TrackerError::http(StatusCode::SERVICE_UNAVAILABLE, None, "Retry-After: 5");
+        assert_eq!(other_status.retry_after(), None);
+    }
 }
@@ -8,10 +8,11 @@ pub mod models;
 pub mod rate_limiter;
 
 pub use client::{FieldRefInput, IssueUpdateExtendedRequest, ListUpdate, ScrollPage, ScrollType, TrackerClient};
-pub use config::{AuthMethod, OrgType, TrackerConfig};
-pub use error::{Result, TrackerError};
+pub use config::{normalize_org_type, AuthMethod, OrgType, TrackerConfig};
+pub use error::{Result, TrackerError, TrackerErrorCode};
 pub use models::{
-    AttachmentMetadata, ChecklistAssignee, ChecklistDeadline, ChecklistDeadlineInput,
-    ChecklistItem, ChecklistItemCreate, ChecklistItemUpdate, Comment, Issue, IssueCreateRequest,
-    IssueFieldRef, SimpleEntityRaw, Transition, TransitionDestination, UserProfile, WorklogEntry,
+    AttachmentMetadata, BulkChangeResponse, ChangelogEntry, ChangelogFieldChange, ChecklistAssignee, ChecklistDeadline, ChecklistDeadlineInput,
+    ChecklistItem, ChecklistItemCreate, ChecklistItemUpdate, Comment, FieldDefinition, Issue, IssueCreateRequest,
+    IssueFieldRef, IssueLink, LinkedIssue, Queue, SimpleEntityRaw, Transition, TransitionDestination, TransitionWithFields,
+    UserProfile, WorklogEntry,
 };
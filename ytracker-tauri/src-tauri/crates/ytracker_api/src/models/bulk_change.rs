@@ -0,0 +1,10 @@
+//! Bulk change models returned by Tracker batch update endpoints.
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+/// Represents an accepted bulk change operation; Tracker applies the patch asynchronously.
+pub struct BulkChangeResponse {
+    #[serde(rename = "id")]
+    pub operation_id: String,
+}
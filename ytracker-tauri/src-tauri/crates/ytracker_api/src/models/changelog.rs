@@ -0,0 +1,29 @@
+//! Issue changelog models returned by Tracker history endpoints.
+
+use crate::models::{CommentAuthor, SimpleEntityRaw};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Represents a single changelog event for an issue, including author, timestamp and the list of changed fields.
+pub struct ChangelogEntry {
+    pub id: Value,
+    pub updated_at: Option<String>,
+    pub updated_by: Option<CommentAuthor>,
+    #[serde(default, rename = "type")]
+    pub change_type: Option<String>,
+    #[serde(default)]
+    pub fields: Option<Vec<ChangelogFieldChange>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Represents a field-level change inside a changelog event with raw previous and new values.
+pub struct ChangelogFieldChange {
+    pub field: Option<SimpleEntityRaw>,
+    #[serde(default)]
+    pub from: Option<Value>,
+    #[serde(default)]
+    pub to: Option<Value>,
+}
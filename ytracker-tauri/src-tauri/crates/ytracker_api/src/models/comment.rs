@@ -19,6 +19,8 @@ pub struct Comment {
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CommentAuthor {
+    #[serde(default)]
+    pub id: Option<Value>,
     pub display: Option<Value>,
     pub login: Option<String>,
     pub email: Option<String>,
@@ -0,0 +1,95 @@
+//! Queue field definition models used to build dynamic issue forms.
+
+use crate::models::SimpleEntityRaw;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Represents an issue field available in a queue, including its type, whether it is required and the allowed values for enumerated fields.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "FieldDefinitionRaw")]
+pub struct FieldDefinition {
+    pub id: String,
+    pub name: Option<Value>,
+    pub type_id: Option<String>,
+    pub required: bool,
+    pub allowed_values: Vec<SimpleEntityRaw>,
+}
+
+/// Wire shape of `GET queues/{key}/fields` entries before normalization.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldDefinitionRaw {
+    id: Option<String>,
+    key: Option<String>,
+    name: Option<Value>,
+    #[serde(default)]
+    schema: Option<FieldSchema>,
+    #[serde(default)]
+    options_provider: Option<FieldOptionsProvider>,
+}
+
+#[derive(Deserialize)]
+struct FieldSchema {
+    #[serde(rename = "type")]
+    type_id: Option<String>,
+    #[serde(default)]
+    required: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct FieldOptionsProvider {
+    #[serde(default)]
+    values: Vec<Value>,
+}
+
+impl From<FieldDefinitionRaw> for FieldDefinition {
+    /// Flattens schema/options payloads and normalizes scalar allowed values into entities.
+    fn from(raw: FieldDefinitionRaw) -> Self {
+        let (type_id, required) = raw
+            .schema
+            .map(|schema| (schema.type_id, schema.required.unwrap_or(false)))
+            .unwrap_or((None, false));
+
+        let allowed_values = raw
+            .options_provider
+            .map(|provider| {
+                provider
+                    .values
+                    .into_iter()
+                    .filter_map(allowed_value_entity)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            id: raw.id.or(raw.key).unwrap_or_default(),
+            name: raw.name,
+            type_id,
+            required,
+            allowed_values,
+        }
+    }
+}
+
+/// Converts an allowed-value payload (object or bare scalar) into a simple entity.
+fn allowed_value_entity(value: Value) -> Option<SimpleEntityRaw> {
+    match value {
+        Value::Object(_) => serde_json::from_value(value).ok(),
+        Value::String(text) if !text.trim().is_empty() => Some(SimpleEntityRaw {
+            id: None,
+            key: Some(text.trim().to_string()),
+            name: None,
+            display: Some(Value::String(text)),
+            extra: HashMap::new(),
+        }),
+        Value::Number(number) => Some(SimpleEntityRaw {
+            id: None,
+            key: Some(number.to_string()),
+            name: None,
+            display: Some(Value::Number(number)),
+            extra: HashMap::new(),
+        }),
+        _ => None,
+    }
+}
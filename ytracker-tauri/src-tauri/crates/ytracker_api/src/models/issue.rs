@@ -22,8 +22,16 @@ pub struct Issue {
     #[serde(default)]
     pub followers: Option<Vec<IssueFieldRef>>,
     #[serde(default)]
+    pub parent: Option<IssueFieldRef>,
+    #[serde(default)]
     pub tags: Option<Vec<String>>,
     #[serde(default)]
+    pub components: Vec<IssueFieldRef>,
+    #[serde(default)]
+    pub deadline: Option<String>,
+    #[serde(default)]
+    pub votes: Option<u64>,
+    #[serde(default)]
     pub spent: Option<Value>,
     #[serde(default)]
     pub time_spent: Option<Value>,
@@ -0,0 +1,26 @@
+//! Issue link models returned by Tracker relation endpoints.
+
+use crate::models::{Issue, SimpleEntityRaw};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Represents a raw link between two issues, including relation type, direction and a reference to the linked issue.
+pub struct IssueLink {
+    pub id: Value,
+    #[serde(default, rename = "type")]
+    pub link_type: Option<SimpleEntityRaw>,
+    #[serde(default)]
+    pub direction: Option<String>,
+    #[serde(default)]
+    pub object: Option<SimpleEntityRaw>,
+}
+
+#[derive(Debug, Clone)]
+/// Represents a linked issue resolved to full issue details together with its relation metadata.
+pub struct LinkedIssue {
+    pub relation_type: String,
+    pub direction: String,
+    pub issue: Issue,
+}
@@ -1,22 +1,32 @@
 //! Tracker API model declarations and re-exports used by the client module.
 
 mod attachment;
+mod bulk_change;
+mod changelog;
 mod checklist;
 mod comment;
+mod field;
 mod issue;
+mod link;
+mod queue;
 mod simple_entity;
 mod transition;
 mod user;
 mod worklog;
 
 pub use attachment::AttachmentMetadata;
+pub use bulk_change::BulkChangeResponse;
+pub use changelog::{ChangelogEntry, ChangelogFieldChange};
 pub use checklist::{
     ChecklistAssignee, ChecklistDeadline, ChecklistDeadlineInput, ChecklistItem,
     ChecklistItemCreate, ChecklistItemUpdate,
 };
 pub use comment::{Comment, CommentAuthor};
+pub use field::FieldDefinition;
 pub use issue::{Issue, IssueCreateRequest, IssueFieldRef};
+pub use link::{IssueLink, LinkedIssue};
+pub use queue::Queue;
 pub use simple_entity::SimpleEntityRaw;
-pub use transition::{Transition, TransitionDestination};
+pub use transition::{Transition, TransitionDestination, TransitionWithFields};
 pub use user::UserProfile;
 pub use worklog::WorklogEntry;
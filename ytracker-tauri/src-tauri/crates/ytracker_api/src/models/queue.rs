@@ -0,0 +1,22 @@
+//! Queue models returned by Tracker queue endpoints.
+
+use super::simple_entity::deserialize_string_field;
+use crate::models::{SimpleEntityRaw, UserProfile};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Represents queue details, including description, lead and the type preselected for new issues.
+pub struct Queue {
+    #[serde(default, deserialize_with = "deserialize_string_field")]
+    pub id: Option<String>,
+    pub key: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub lead: Option<UserProfile>,
+    #[serde(default)]
+    pub default_type: Option<SimpleEntityRaw>,
+}
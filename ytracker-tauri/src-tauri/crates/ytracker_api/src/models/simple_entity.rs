@@ -19,7 +19,7 @@ pub struct SimpleEntityRaw {
     pub extra: HashMap<String, Value>,
 }
 /// Normalized entity model with stable key/id and display value.
-fn deserialize_string_field<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+pub(crate) fn deserialize_string_field<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -36,9 +36,6 @@ where
         Value::Number(number) => Some(number.to_string()),
         Value::Bool(flag) => Some(flag.to_string()),
         Value::Null => None,
-        other => match serde_json::to_string(&other) {
-            Ok(serialized) => Some(serialized),
-            Err(_) => None,
-        },
+        other => serde_json::to_string(&other).ok(),
     }))
 }
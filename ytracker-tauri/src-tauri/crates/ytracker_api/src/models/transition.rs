@@ -1,5 +1,6 @@
 //! Workflow transition models for issue state changes.
 
+use crate::models::FieldDefinition;
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -19,6 +20,39 @@ pub struct Transition {
     pub status: Option<TransitionDestination>,
 }
 
+/// Represents a workflow transition together with the fields of its transition screen.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "TransitionWithFieldsRaw")]
+pub struct TransitionWithFields {
+    pub transition: Transition,
+    pub required_fields: Vec<FieldDefinition>,
+}
+
+/// Wire shape of a transition carrying an optional `screen.fields` array.
+#[derive(Deserialize)]
+struct TransitionWithFieldsRaw {
+    #[serde(flatten)]
+    transition: Transition,
+    #[serde(default)]
+    screen: Option<TransitionScreen>,
+}
+
+#[derive(Deserialize)]
+struct TransitionScreen {
+    #[serde(default)]
+    fields: Vec<FieldDefinition>,
+}
+
+impl From<TransitionWithFieldsRaw> for TransitionWithFields {
+    /// Lifts screen fields next to the transition; transitions without a screen get no fields.
+    fn from(raw: TransitionWithFieldsRaw) -> Self {
+        Self {
+            transition: raw.transition,
+            required_fields: raw.screen.map(|screen| screen.fields).unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 /// Represents transition destination status payload with stable key/id and display/name values.
@@ -1,11 +1,14 @@
 //! User profile models returned by Tracker identity endpoints.
 
+use super::simple_entity::deserialize_string_field;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 /// Represents user profile information returned by Tracker API, including display/login/email and avatar metadata.
 pub struct UserProfile {
+    #[serde(default, deserialize_with = "deserialize_string_field")]
+    pub uid: Option<String>,
     pub display: Option<String>,
     pub login: Option<String>,
     pub email: Option<String>,
@@ -1,43 +1,117 @@
 //! Lightweight async rate limiter used for API request pacing.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use tokio::sync::Mutex;
 use tokio::time::sleep;
 
-
 /// Represents a simple async rate limiter that enforces a minimum cooldown interval between hits.
 #[derive(Clone, Debug)]
 pub struct RateLimiter {
-    cooldown: Duration,
+    base_cooldown: Duration,
+    cooldown: Arc<Mutex<Duration>>,
     last_call: Arc<Mutex<Option<Instant>>>,
+    metrics: Option<Arc<RateLimiterMetrics>>,
+}
+
+/// Shared counters describing how often the limiter was hit and had to delay a request.
+#[derive(Debug, Default)]
+pub struct RateLimiterMetrics {
+    total_hits: AtomicU64,
+    throttled_hits: AtomicU64,
+    last_throttle_ms: AtomicU64,
+}
+
+/// Point-in-time copy of limiter metrics.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimiterMetricsSnapshot {
+    pub total_hits: u64,
+    pub throttled_hits: u64,
+    pub last_throttle_duration: Option<Duration>,
+}
+
+impl RateLimiterMetrics {
+    /// Returns current counter values.
+    pub fn snapshot(&self) -> RateLimiterMetricsSnapshot {
+        let throttled_hits = self.throttled_hits.load(Ordering::Relaxed);
+        RateLimiterMetricsSnapshot {
+            total_hits: self.total_hits.load(Ordering::Relaxed),
+            throttled_hits,
+            last_throttle_duration: (throttled_hits > 0)
+                .then(|| Duration::from_millis(self.last_throttle_ms.load(Ordering::Relaxed))),
+        }
+    }
+
+    fn record(&self, throttle: Option<Duration>) {
+        self.total_hits.fetch_add(1, Ordering::Relaxed);
+        if let Some(delay) = throttle {
+            self.throttled_hits.fetch_add(1, Ordering::Relaxed);
+            let millis = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+            self.last_throttle_ms.store(millis, Ordering::Relaxed);
+        }
+    }
 }
 
 impl RateLimiter {
     /// Creates a limiter that enforces a minimum delay between requests.
     pub fn new(cooldown: Duration) -> Self {
         Self {
-            cooldown,
+            base_cooldown: cooldown,
+            cooldown: Arc::new(Mutex::new(cooldown)),
             last_call: Arc::new(Mutex::new(None)),
+            metrics: None,
         }
     }
 
+    /// Creates a limiter that also records hit and throttle counters.
+    pub fn with_metrics(cooldown: Duration) -> (Self, Arc<RateLimiterMetrics>) {
+        let metrics = Arc::new(RateLimiterMetrics::default());
+        let limiter = Self {
+            metrics: Some(metrics.clone()),
+            ..Self::new(cooldown)
+        };
+        (limiter, metrics)
+    }
+
     /// Waits until cooldown is satisfied, then records current call timestamp.
     pub async fn hit(&self) {
         let mut guard = self.last_call.lock().await;
+        let cooldown = *self.cooldown.lock().await;
+        let mut throttle = None;
         if let Some(last) = *guard {
             let elapsed = last.elapsed();
-            if elapsed < self.cooldown {
-                sleep(self.cooldown - elapsed).await;
+            if elapsed < cooldown {
+                let delay = cooldown - elapsed;
+                throttle = Some(delay);
+                sleep(delay).await;
             }
         }
         *guard = Some(Instant::now());
+        if let Some(metrics) = &self.metrics {
+            metrics.record(throttle);
+        }
+    }
+
+    /// Returns shared metrics counters when the limiter was built with `with_metrics`.
+    pub fn metrics(&self) -> Option<Arc<RateLimiterMetrics>> {
+        self.metrics.clone()
     }
 
-    /// Returns configured cooldown interval.
-    pub fn cooldown(&self) -> Duration {
-        self.cooldown
+    /// Returns currently effective cooldown interval.
+    pub async fn cooldown(&self) -> Duration {
+        *self.cooldown.lock().await
+    }
+
+    /// Replaces cooldown for all clones of this limiter, e.g. to back off after HTTP 429.
+    pub async fn update_cooldown(&self, new_cooldown: Duration) {
+        *self.cooldown.lock().await = new_cooldown;
+    }
+
+    /// Restores cooldown to the value the limiter was created with.
+    pub async fn reset_cooldown(&self) {
+        *self.cooldown.lock().await = self.base_cooldown;
     }
 }
 
@@ -49,7 +123,24 @@ mod tests {
     #[tokio::test]
     async fn cooldown_accessor_returns_configured_value() {
         let limiter = RateLimiter::new(Duration::from_millis(25));
-        assert_eq!(limiter.cooldown(), Duration::from_millis(25));
+        assert_eq!(limiter.cooldown().await, Duration::from_millis(25));
+    }
+
+    #[tokio::test]
+    async fn update_and_reset_cooldown_apply_to_clones() {
+        let limiter = RateLimiter::new(Duration::from_millis(10));
+        let shared = limiter.clone();
+
+        limiter.update_cooldown(Duration::from_millis(60)).await;
+        assert_eq!(shared.cooldown().await, Duration::from_millis(60));
+
+        shared.hit().await;
+        let start = Instant::now();
+        shared.hit().await;
+        assert!(start.elapsed() >= Duration::from_millis(55));
+
+        shared.reset_cooldown().await;
+        assert_eq!(limiter.cooldown().await, Duration::from_millis(10));
     }
 
     #[tokio::test]
@@ -62,4 +153,19 @@ mod tests {
 
         assert!(start.elapsed() >= Duration::from_millis(35));
     }
+
+    #[tokio::test]
+    async fn with_metrics_counts_hits_and_throttles() {
+        let (limiter, metrics) = RateLimiter::with_metrics(Duration::from_millis(30));
+        assert_eq!(metrics.snapshot().total_hits, 0);
+
+        limiter.hit().await;
+        limiter.clone().hit().await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.total_hits, 2);
+        assert_eq!(snapshot.throttled_hits, 1);
+        assert!(snapshot.last_throttle_duration.is_some());
+        assert!(RateLimiter::new(Duration::from_millis(1)).metrics().is_none());
+    }
 }
@@ -16,8 +16,13 @@ pub struct Issue {
     pub issue_type: Option<SimpleEntity>,
     pub assignee: Option<SimpleEntity>,
     pub tags: Vec<String>,
+    pub components: Vec<String>,
     pub followers: Vec<SimpleEntity>,
     pub tracked_seconds: Option<u64>,
+    pub parent_key: Option<String>,
+    pub parent_summary: Option<String>,
+    pub deadline: Option<String>,
+    pub votes: Option<u64>,
 }
 
 /// Represents a simple key/display pair for dynamic issue fields like status and priority.
@@ -47,6 +52,7 @@ pub struct Comment {
     pub id: String,
     pub text: String,
     pub author: String,
+    pub author_login: Option<String>,
     pub created_at: String,
 }
 
@@ -64,7 +70,18 @@ pub struct Attachment {
 pub struct Transition {
     pub id: String,
     pub name: String,
+    pub description: Option<String>,
+    pub to_status: Option<Status>,
+}
+
+/// Workflow transition DTO with the fields its transition screen asks for.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransitionWithFields {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
     pub to_status: Option<Status>,
+    pub required_fields: Vec<FieldDefinition>,
 }
 
 /// Represents a simple key/display pair for dynamic issue fields like status and priority.
@@ -77,6 +94,7 @@ pub struct AttachmentPreview {
 /// Represents a user profile returned by Tracker API, including display name, login, email and avatar URL.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UserProfile {
+    pub uid: Option<String>,
     pub display: Option<String>,
     pub login: Option<String>,
     pub email: Option<String>,
@@ -91,8 +109,49 @@ pub struct WorklogEntry {
     pub duration_seconds: u64,
     pub comment: String,
     pub author: String,
+    pub issue_key: Option<String>,
+}
+
+/// Per-issue slice of logged time for a single day.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DailySummaryEntry {
+    pub issue_key: String,
+    pub issue_summary: String,
+    pub seconds: u64,
 }
 
+/// Logged time for one local calendar day, with the underlying worklog entries.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DaySummary {
+    pub date: String,
+    pub total_seconds: u64,
+    pub entries: Vec<WorklogEntry>,
+}
+
+/// Issue linked to another issue, with relation type and link direction.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LinkedIssue {
+    pub relation_type: String,
+    pub direction: String,
+    pub issue: Issue,
+}
+
+/// Single field change from an issue changelog event.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChangelogEntry {
+    pub id: String,
+    pub timestamp: String,
+    pub author: String,
+    pub field: String,
+    pub from_value: Option<String>,
+    pub to_value: Option<String>,
+}
+
+/// Issue change history serialized as a flat array of field changes.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct IssueHistory(pub Vec<ChangelogEntry>);
+
 /// Checklist item DTO sent to the frontend.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChecklistItem {
@@ -106,6 +165,14 @@ pub struct ChecklistItem {
     pub item_type: Option<String>,
 }
 
+/// Checklist completion summary for issue cards.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChecklistProgress {
+    pub total: usize,
+    pub checked: usize,
+    pub percent: u8,
+}
+
 /// Payload received from the frontend to create a checklist item.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChecklistItemCreatePayload {
@@ -134,3 +201,54 @@ pub struct ChecklistItemUpdatePayload {
     #[serde(default)]
     pub deadline_type: Option<String>,
 }
+
+/// Queue field definition DTO used to render dynamic issue create/edit forms.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FieldDefinition {
+    pub id: String,
+    pub name: String,
+    pub type_id: Option<String>,
+    pub required: bool,
+    pub allowed_values: Vec<SimpleEntity>,
+}
+
+/// Queue details DTO for the queue detail view.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Queue {
+    pub id: Option<String>,
+    pub key: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub lead: Option<UserProfile>,
+    pub default_type: Option<SimpleEntity>,
+}
+
+/// PKCE verifier/challenge pair DTO; the verifier must be kept until the code exchange.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PkcePair {
+    pub code_verifier: String,
+    pub code_challenge: String,
+}
+
+/// Accepted bulk change operation DTO; the patch is applied by Tracker asynchronously.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BulkChangeResult {
+    pub operation_id: String,
+}
+
+/// Snapshot of API rate limiter counters for diagnostics.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RateLimiterMetrics {
+    pub total_hits: u64,
+    pub throttled_hits: u64,
+    pub last_throttle_ms: Option<u64>,
+}
+
+/// App build and Tracker API version details for the about page and bug reports.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VersionInfo {
+    pub app_version: String,
+    pub api_version: String,
+    pub build_date: Option<String>,
+    pub platform: String,
+}
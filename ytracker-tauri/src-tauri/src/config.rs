@@ -1,8 +1,30 @@
 //! Persistent desktop configuration model and file-backed manager.
 
+use log::warn;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+/// Minimum spacing between reloads triggered by external edits, to absorb editor save storms.
+const RELOAD_MIN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long the config file must stay untouched before a burst of edits is read.
+const RELOAD_QUIET_PERIOD: Duration = Duration::from_millis(300);
+
+/// Compact JSON of the config last written by [`ConfigManager::save`] or announced by the
+/// watcher, shared so the watcher does not re-announce the app's own saves.
+static LAST_KNOWN_JSON: Mutex<Option<String>> = Mutex::new(None);
+
+/// Current on-disk config schema version; imports from newer versions are rejected.
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
+/// Default largest attachment, in bytes, that is fetched for an inline preview.
+pub const DEFAULT_PREVIEW_MAX_BYTES: u64 = 8 * 1024 * 1024;
 
 /// Default configured workday hours.
 fn default_workday_hours() -> u8 {
@@ -19,10 +41,16 @@ fn default_workday_end_time() -> String {
     "17:00".to_string()
 }
 
+/// Default attachment preview size limit.
+fn default_preview_max_bytes() -> u64 {
+    DEFAULT_PREVIEW_MAX_BYTES
+}
+
 /// Represents the application configuration persisted on disk, including timer notification interval and workday settings.
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(default)]
 pub struct Config {
+    pub schema_version: u32,
     pub timer_notification_interval: u32,
     #[serde(default = "default_workday_hours")]
     pub workday_hours: u8,
@@ -30,23 +58,87 @@ pub struct Config {
     pub workday_start_time: String,
     #[serde(default = "default_workday_end_time")]
     pub workday_end_time: String,
+    pub auto_log_on_timer_stop: bool,
+    pub auto_log_min_seconds: u64,
+    pub custom_motivational_phrases: Vec<String>,
+    pub tray_group_by_queue: bool,
+    pub workday_start_reminder: bool,
+    pub break_reminder_enabled: bool,
+    pub break_reminder_minutes: u32,
+    pub proxy_url: Option<String>,
+    pub no_proxy: Option<String>,
+    pub workday_timezone: Option<String>,
+    pub issue_display_fields: Vec<String>,
+    #[serde(default = "default_preview_max_bytes")]
+    pub preview_max_bytes: u64,
 }
 
 impl Default for Config {
     /// Returns baseline config when no persisted settings are available.
     fn default() -> Self {
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             timer_notification_interval: 15,
             workday_hours: default_workday_hours(),
             workday_start_time: default_workday_start_time(),
             workday_end_time: default_workday_end_time(),
+            auto_log_on_timer_stop: false,
+            auto_log_min_seconds: 0,
+            custom_motivational_phrases: Vec::new(),
+            tray_group_by_queue: false,
+            workday_start_reminder: false,
+            break_reminder_enabled: false,
+            break_reminder_minutes: 0,
+            proxy_url: None,
+            no_proxy: None,
+            workday_timezone: None,
+            issue_display_fields: Vec::new(),
+            preview_max_bytes: default_preview_max_bytes(),
         }
     }
 }
 
+impl Config {
+    /// Parses an exported config JSON document, rejecting unsupported schema versions.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let config: Config =
+            serde_json::from_str(json).map_err(|err| format!("Invalid config JSON: {}", err))?;
+        if config.schema_version == 0 || config.schema_version > CONFIG_SCHEMA_VERSION {
+            return Err(format!(
+                "Unsupported config schema version {} (expected at most {})",
+                config.schema_version, CONFIG_SCHEMA_VERSION
+            ));
+        }
+        Ok(config)
+    }
+}
+
+/// In-memory copy of the normalized config shared across the app, so hot paths such as
+/// tray refreshes do not read `config.json` on every call.
+#[derive(Clone)]
+pub struct ConfigCache(Arc<Mutex<Config>>);
+
+impl ConfigCache {
+    /// Creates a cache seeded with an already-normalized config.
+    pub fn new(config: Config) -> Self {
+        Self(Arc::new(Mutex::new(config)))
+    }
+
+    /// Returns a copy of the cached config.
+    pub fn get(&self) -> Config {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Replaces the cached config.
+    pub fn set(&self, config: Config) {
+        *self.0.lock().unwrap() = config;
+    }
+}
+
 /// Manages loading and saving of application configuration to a JSON file in the platform-specific config directory.
 pub struct ConfigManager {
     path: PathBuf,
+    app: Option<tauri::AppHandle>,
 }
 
 impl ConfigManager {
@@ -58,7 +150,15 @@ impl ConfigManager {
         let dirs = directories::ProjectDirs::from("ru", "sovego", "ytracker")
             .expect("Could not determine config directory");
         let path = dirs.config_dir().join("config.json");
-        Self { path }
+        Self { path, app: None }
+    }
+
+    /// Creates a manager that can emit config events through the given app handle.
+    pub fn with_app_handle(app: tauri::AppHandle) -> Self {
+        Self {
+            app: Some(app),
+            ..Self::new()
+        }
     }
 
     /// Loads config from disk, falling back to defaults on read/parse errors.
@@ -78,13 +178,100 @@ impl ConfigManager {
         }
         let content = serde_json::to_string_pretty(config)?;
         fs::write(&self.path, content)?;
+        *LAST_KNOWN_JSON.lock().unwrap() = serde_json::to_string(config).ok();
+        Ok(())
+    }
+
+    /// Watches the config file for external edits and emits `config-changed` with the
+    /// reloaded, normalized config.
+    ///
+    /// A burst of edits is read once the file has been quiet for [`RELOAD_QUIET_PERIOD`], and
+    /// reloads are spaced at least [`RELOAD_MIN_INTERVAL`] apart. Files that fail to parse
+    /// (half-written or invalid JSON) are ignored. Saves made through [`ConfigManager::save`]
+    /// are not re-announced; callers emit their own events for those.
+    pub fn watch(self, normalize: fn(Config) -> Config) -> Result<(), String> {
+        let app = self
+            .app
+            .clone()
+            .ok_or_else(|| "Config watcher requires an app handle".to_string())?;
+        let dir = self
+            .path
+            .parent()
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| "Config path has no parent directory".to_string())?;
+        fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+
+        // Watch the directory rather than the file: editors often save by renaming a temp file.
+        let file_name = self.path.file_name().map(ToOwned::to_owned);
+        let (tx, rx) = mpsc::channel::<()>();
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+            let Ok(event) = result else {
+                return;
+            };
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            let touches_config = event
+                .paths
+                .iter()
+                .any(|path| path.file_name() == file_name.as_deref());
+            if touches_config {
+                let _ = tx.send(());
+            }
+        })
+        .map_err(|err| err.to_string())?;
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|err| err.to_string())?;
+
+        thread::spawn(move || {
+            // The watcher lives as long as this thread so the channel stays connected.
+            let _watcher = watcher;
+            LAST_KNOWN_JSON.lock().unwrap().get_or_insert_with(|| {
+                serde_json::to_string(&normalize(self.load())).unwrap_or_default()
+            });
+            let mut last_reload: Option<Instant> = None;
+
+            while rx.recv().is_ok() {
+                while rx.recv_timeout(RELOAD_QUIET_PERIOD).is_ok() {}
+                let wait = last_reload.and_then(|at| RELOAD_MIN_INTERVAL.checked_sub(at.elapsed()));
+                if let Some(wait) = wait {
+                    thread::sleep(wait);
+                }
+                while rx.try_recv().is_ok() {}
+                last_reload = Some(Instant::now());
+
+                let Ok(content) = fs::read_to_string(&self.path) else {
+                    continue;
+                };
+                let config = match serde_json::from_str::<Config>(&content) {
+                    Ok(config) => normalize(config),
+                    Err(err) => {
+                        warn!("Ignoring unparsable config.json change: {}", err);
+                        continue;
+                    }
+                };
+                let json = serde_json::to_string(&config).ok();
+                {
+                    let mut last_json = LAST_KNOWN_JSON.lock().unwrap();
+                    if json == *last_json {
+                        continue;
+                    }
+                    *last_json = json;
+                }
+                if let Err(err) = app.emit("config-changed", &config) {
+                    warn!("Failed to emit config-changed event: {}", err);
+                }
+            }
+        });
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ConfigManager};
+    use super::{Config, ConfigManager, CONFIG_SCHEMA_VERSION, DEFAULT_PREVIEW_MAX_BYTES};
     use std::env;
     use std::fs;
     use std::path::PathBuf;
@@ -101,16 +288,29 @@ mod tests {
     #[test]
     fn default_config_has_expected_values() {
         let config = Config::default();
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
         assert_eq!(config.timer_notification_interval, 15);
         assert_eq!(config.workday_hours, 8);
         assert_eq!(config.workday_start_time, "09:00");
         assert_eq!(config.workday_end_time, "17:00");
+        assert!(!config.auto_log_on_timer_stop);
+        assert_eq!(config.auto_log_min_seconds, 0);
+        assert!(config.custom_motivational_phrases.is_empty());
+        assert!(!config.tray_group_by_queue);
+        assert!(!config.workday_start_reminder);
+        assert!(!config.break_reminder_enabled);
+        assert_eq!(config.break_reminder_minutes, 0);
+        assert!(config.proxy_url.is_none());
+        assert!(config.no_proxy.is_none());
+        assert!(config.workday_timezone.is_none());
+        assert!(config.issue_display_fields.is_empty());
+        assert_eq!(config.preview_max_bytes, DEFAULT_PREVIEW_MAX_BYTES);
     }
 
     #[test]
     fn load_missing_file_returns_default() {
         let path = unique_path("missing");
-        let manager = ConfigManager { path };
+        let manager = ConfigManager { path, app: None };
 
         let loaded = manager.load();
         assert_eq!(loaded.timer_notification_interval, 15);
@@ -122,12 +322,28 @@ mod tests {
         let path = unique_path("roundtrip");
         let parent = path.parent().map(ToOwned::to_owned);
 
-        let manager = ConfigManager { path: path.clone() };
+        let manager = ConfigManager {
+            path: path.clone(),
+            app: None,
+        };
         let config = Config {
+            schema_version: CONFIG_SCHEMA_VERSION,
             timer_notification_interval: 30,
             workday_hours: 7,
             workday_start_time: "10:15".to_string(),
             workday_end_time: "18:45".to_string(),
+            auto_log_on_timer_stop: true,
+            auto_log_min_seconds: 300,
+            custom_motivational_phrases: vec!["Keep going".to_string()],
+            tray_group_by_queue: true,
+            workday_start_reminder: true,
+            break_reminder_enabled: true,
+            break_reminder_minutes: 50,
+            proxy_url: Some("http://proxy.local:3128".to_string()),
+            no_proxy: Some("localhost,.corp.local".to_string()),
+            workday_timezone: Some("Europe/Moscow".to_string()),
+            issue_display_fields: vec!["key".to_string(), "summary".to_string()],
+            preview_max_bytes: 1024,
         };
 
         manager.save(&config).expect("save should succeed");
@@ -137,6 +353,18 @@ mod tests {
         assert_eq!(loaded.workday_hours, 7);
         assert_eq!(loaded.workday_start_time, "10:15");
         assert_eq!(loaded.workday_end_time, "18:45");
+        assert!(loaded.auto_log_on_timer_stop);
+        assert_eq!(loaded.auto_log_min_seconds, 300);
+        assert_eq!(loaded.custom_motivational_phrases, vec!["Keep going".to_string()]);
+        assert!(loaded.tray_group_by_queue);
+        assert!(loaded.workday_start_reminder);
+        assert!(loaded.break_reminder_enabled);
+        assert_eq!(loaded.break_reminder_minutes, 50);
+        assert_eq!(loaded.proxy_url.as_deref(), Some("http://proxy.local:3128"));
+        assert_eq!(loaded.no_proxy.as_deref(), Some("localhost,.corp.local"));
+        assert_eq!(loaded.workday_timezone.as_deref(), Some("Europe/Moscow"));
+        assert_eq!(loaded.issue_display_fields, vec!["key", "summary"]);
+        assert_eq!(loaded.preview_max_bytes, 1024);
 
         if let Some(parent) = parent {
             let _ = fs::remove_dir_all(parent);
@@ -150,11 +378,28 @@ mod tests {
         fs::create_dir_all(parent).expect("create temp directory");
         fs::write(&path, "not-valid-json").expect("write invalid config");
 
-        let manager = ConfigManager { path: path.clone() };
+        let manager = ConfigManager {
+            path: path.clone(),
+            app: None,
+        };
         let loaded = manager.load();
         assert_eq!(loaded.timer_notification_interval, 15);
         assert_eq!(loaded.workday_start_time, "09:00");
 
         let _ = fs::remove_dir_all(parent);
     }
+
+    #[test]
+    fn from_json_validates_schema_version() {
+        let exported = serde_json::to_string_pretty(&Config::default()).expect("serialize");
+        let imported = Config::from_json(&exported).expect("current version imports");
+        assert_eq!(imported.schema_version, CONFIG_SCHEMA_VERSION);
+
+        let legacy = Config::from_json(r#"{"workday_hours": 6}"#).expect("legacy imports");
+        assert_eq!(legacy.workday_hours, 6);
+
+        let future = format!(r#"{{"schema_version": {}}}"#, CONFIG_SCHEMA_VERSION + 1);
+        assert!(Config::from_json(&future).is_err());
+        assert!(Config::from_json("not-valid-json").is_err());
+    }
 }
@@ -1,12 +1,20 @@
 //! In-memory issue cache used to enrich timer/worklog operations.
 
 use crate::bridge::Issue;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 
+const RECENT_ISSUES_CAPACITY: usize = 5;
+
+/// Name of the store backing the main issue list and tray.
+pub const DEFAULT_STORE_NAME: &str = "default";
+
 /// Thread-safe in-memory store for currently loaded issues, allowing quick access to issue details without repeated API calls.
 #[derive(Clone, Default)]
 pub struct IssueStore {
     issues: Arc<Mutex<Vec<Issue>>>,
+    recent_issues: Arc<Mutex<VecDeque<Issue>>>,
+    today_logged_seconds: Arc<Mutex<u64>>,
 }
 
 impl IssueStore {
@@ -30,4 +38,252 @@ impl IssueStore {
             .find(|issue| issue.key == key)
             .cloned()
     }
+
+    /// Returns cached issues whose status key matches exactly.
+    pub fn find_by_status(&self, status_key: &str) -> Vec<Issue> {
+        self.issues
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|issue| issue.status.key == status_key)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns cached issues whose assignee display name matches, ignoring case.
+    pub fn find_by_assignee(&self, assignee_display: &str) -> Vec<Issue> {
+        let needle = assignee_display.trim().to_lowercase();
+        self.issues
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|issue| {
+                issue
+                    .assignee
+                    .as_ref()
+                    .is_some_and(|assignee| assignee.display.trim().to_lowercase() == needle)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Counts cached issues grouped by status key.
+    pub fn count_by_status(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for issue in self.issues.lock().unwrap().iter() {
+            *counts.entry(issue.status.key.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns number of cached issues.
+    pub fn count_total(&self) -> usize {
+        self.issues.lock().unwrap().len()
+    }
+
+    /// Drops an issue from the cache and the recently used list, e.g. after its key changed.
+    pub fn remove_one(&self, issue_key: &str) {
+        self.issues
+            .lock()
+            .unwrap()
+            .retain(|issue| issue.key != issue_key);
+        self.recent_issues
+            .lock()
+            .unwrap()
+            .retain(|issue| issue.key != issue_key);
+    }
+
+    /// Moves a cached issue to the front of the recently used list, evicting the oldest entry.
+    pub fn update_last_used(&self, issue_key: &str) {
+        let Some(issue) = self.find(issue_key) else {
+            return;
+        };
+        let mut recent = self.recent_issues.lock().unwrap();
+        recent.retain(|entry| entry.key != issue_key);
+        recent.push_front(issue);
+        recent.truncate(RECENT_ISSUES_CAPACITY);
+    }
+
+    /// Returns recently used issues, most recent first.
+    pub fn recent_issues(&self) -> Vec<Issue> {
+        self.recent_issues.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Stores the latest known total of seconds logged today.
+    pub fn set_today_logged_seconds(&self, seconds: u64) {
+        *self.today_logged_seconds.lock().unwrap() = seconds;
+    }
+
+    /// Returns cached total of seconds logged today without hitting the API.
+    pub fn today_logged_seconds(&self) -> u64 {
+        *self.today_logged_seconds.lock().unwrap()
+    }
+}
+
+/// Named issue stores for saved queries, with the main cache registered as [`DEFAULT_STORE_NAME`].
+#[derive(Clone)]
+pub struct IssueStoreRegistry {
+    stores: Arc<Mutex<HashMap<String, IssueStore>>>,
+}
+
+impl IssueStoreRegistry {
+    /// Creates a registry whose default entry shares state with the given store.
+    pub fn new(default_store: IssueStore) -> Self {
+        let mut stores = HashMap::new();
+        stores.insert(DEFAULT_STORE_NAME.to_string(), default_store);
+        Self {
+            stores: Arc::new(Mutex::new(stores)),
+        }
+    }
+
+    /// Returns the named store, if one has been created.
+    pub fn get(&self, name: &str) -> Option<IssueStore> {
+        self.stores.lock().unwrap().get(name).cloned()
+    }
+
+    /// Returns the named store, creating an empty one on first use.
+    pub fn get_or_create(&self, name: &str) -> IssueStore {
+        self.stores
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IssueStore, IssueStoreRegistry, DEFAULT_STORE_NAME, RECENT_ISSUES_CAPACITY};
+    use crate::bridge::{Issue, Priority, SimpleEntity, Status};
+
+    fn issue(key: &str) -> Issue {
+        Issue {
+            key: key.to_string(),
+            summary: format!("Summary {key}"),
+            description: String::new(),
+            status: Status {
+                key: "open".to_string(),
+                display: "Open".to_string(),
+            },
+            priority: Priority {
+                key: "normal".to_string(),
+                display: "Normal".to_string(),
+            },
+            issue_type: None,
+            assignee: None,
+            tags: Vec::new(),
+            components: Vec::new(),
+            followers: Vec::new(),
+            tracked_seconds: None,
+            parent_key: None,
+            parent_summary: None,
+            deadline: None,
+            votes: None,
+        }
+    }
+
+    fn recent_keys(store: &IssueStore) -> Vec<String> {
+        store
+            .recent_issues()
+            .into_iter()
+            .map(|issue| issue.key)
+            .collect()
+    }
+
+    #[test]
+    fn update_last_used_moves_issue_to_front_without_duplicates() {
+        let store = IssueStore::default();
+        store.set(vec![issue("YT-1"), issue("YT-2")]);
+
+        store.update_last_used("YT-1");
+        store.update_last_used("YT-2");
+        store.update_last_used("YT-1");
+        store.update_last_used("YT-404");
+
+        assert_eq!(recent_keys(&store), vec!["YT-1", "YT-2"]);
+    }
+
+    #[test]
+    fn remove_one_drops_issue_from_cache_and_recent_list() {
+        let store = IssueStore::default();
+        store.set(vec![issue("YT-1"), issue("YT-2")]);
+        store.update_last_used("YT-1");
+
+        store.remove_one("YT-1");
+
+        assert!(store.find("YT-1").is_none());
+        assert!(store.find("YT-2").is_some());
+        assert!(recent_keys(&store).is_empty());
+    }
+
+    #[test]
+    fn find_by_status_and_assignee_filter_cached_issues() {
+        let store = IssueStore::default();
+        let mut assigned = issue("YT-2");
+        assigned.assignee = Some(SimpleEntity {
+            key: "jane".to_string(),
+            display: "Jane Doe".to_string(),
+        });
+        assigned.status.key = "inProgress".to_string();
+        store.set(vec![issue("YT-1"), assigned]);
+
+        let open: Vec<String> = store
+            .find_by_status("open")
+            .into_iter()
+            .map(|issue| issue.key)
+            .collect();
+        assert_eq!(open, vec!["YT-1"]);
+        assert_eq!(store.find_by_assignee(" jane doe ").len(), 1);
+        assert!(store.find_by_assignee("John").is_empty());
+    }
+
+    #[test]
+    fn count_by_status_groups_cached_issues() {
+        let store = IssueStore::default();
+        let mut closed = issue("YT-3");
+        closed.status.key = "closed".to_string();
+        store.set(vec![issue("YT-1"), issue("YT-2"), closed]);
+
+        let counts = store.count_by_status();
+        assert_eq!(counts.get("open"), Some(&2));
+        assert_eq!(counts.get("closed"), Some(&1));
+        assert_eq!(store.count_total(), 3);
+    }
+
+    #[test]
+    fn update_last_used_caps_recent_list() {
+        let store = IssueStore::default();
+        let keys: Vec<String> = (1..=7).map(|index| format!("YT-{index}")).collect();
+        store.set(keys.iter().map(|key| issue(key)).collect());
+
+        for key in &keys {
+            store.update_last_used(key);
+        }
+
+        let recent = recent_keys(&store);
+        assert_eq!(recent.len(), RECENT_ISSUES_CAPACITY);
+        assert_eq!(recent.first().map(String::as_str), Some("YT-7"));
+    }
+
+    #[test]
+    fn registry_keeps_named_stores_independent() {
+        let default_store = IssueStore::default();
+        let registry = IssueStoreRegistry::new(default_store.clone());
+
+        default_store.set(vec![issue("YT-1")]);
+        registry
+            .get_or_create("bugs")
+            .set(vec![issue("YT-2"), issue("YT-3")]);
+
+        let shared = registry
+            .get(DEFAULT_STORE_NAME)
+            .expect("default store exists");
+        assert_eq!(shared.count_total(), 1);
+        assert_eq!(
+            registry.get("bugs").map(|store| store.count_total()),
+            Some(2)
+        );
+        assert!(registry.get("review").is_none());
+    }
 }
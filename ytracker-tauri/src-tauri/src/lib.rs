@@ -5,64 +5,154 @@
 
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
-use chrono::{DateTime, Duration, Local, NaiveTime, Utc};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
 use directories::UserDirs;
+use futures_util::StreamExt;
 use log::{debug, info, warn};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Serialize;
 use serde_json::{Map as JsonMap, Value};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIconBuilder;
-use tauri::{Emitter, Manager, Runtime};
-#[allow(unused_imports)]
+use tauri::{Emitter, Listener, Manager, Runtime};
 use tauri_plugin_dialog::DialogExt;
 use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_opener::OpenerExt;
 use tauri_plugin_updater::{Error as UpdaterError, Update, UpdaterExt};
 use tokio::{fs as async_fs, task, time::sleep};
 
 mod config;
 mod issue_store;
 mod bridge;
+mod scroll_contexts;
 mod secrets;
 mod timer;
-use config::{Config, ConfigManager};
-use issue_store::IssueStore;
-use secrets::{ClientCredentialsInfo, SecretsManager, SessionToken};
+use config::{
+    Config, ConfigCache, ConfigManager, CONFIG_SCHEMA_VERSION, DEFAULT_PREVIEW_MAX_BYTES,
+};
+use issue_store::{IssueStore, IssueStoreRegistry, DEFAULT_STORE_NAME};
+use scroll_contexts::{PrefetchedPages, ScrollContextRegistry};
+use secrets::{ClientCredentialsInfo, ProxySettings, SecretsManager, SessionToken};
 use timer::Timer;
 use ytracker_api::models::CommentAuthor as NativeCommentAuthor;
 use ytracker_api::rate_limiter::RateLimiter;
 use ytracker_api::client::{FieldRefInput, IssueSearchParams, IssueUpdateExtendedRequest, ListUpdate};
+use ytracker_api::auth::AuthMode;
 use ytracker_api::{
-    auth, AttachmentMetadata as NativeAttachment, Comment as NativeComment,
+    auth, AttachmentMetadata as NativeAttachment, ChangelogEntry as NativeChangelogEntry,
+    Comment as NativeComment,
     ChecklistItem as NativeChecklistItem, ChecklistItemCreate, ChecklistItemUpdate,
-    ChecklistDeadlineInput,
+    ChecklistDeadlineInput, FieldDefinition as NativeFieldDefinition,
     Issue as NativeIssue, IssueCreateRequest,
-    IssueFieldRef as NativeIssueFieldRef, OrgType, ScrollType, SimpleEntityRaw as NativeSimpleEntity,
-    TrackerClient, TrackerConfig, Transition as NativeTransition, UserProfile as NativeUserProfile,
+    IssueFieldRef as NativeIssueFieldRef, LinkedIssue as NativeLinkedIssue, OrgType, Queue as NativeQueue, ScrollPage, ScrollType, SimpleEntityRaw as NativeSimpleEntity,
+    TrackerClient, TrackerConfig, TrackerError, Transition as NativeTransition,
+    TransitionWithFields as NativeTransitionWithFields, UserProfile as NativeUserProfile,
     WorklogEntry as NativeWorklogEntry,
 };
 
 static DURATION_TOKEN_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(\d+)\s*(w|d|h|m)").expect("invalid duration regex"));
+    Lazy::new(|| Regex::new(r"(\d+)\s*(w|d|h|m|s)").expect("invalid duration regex"));
+static ISO_DURATION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^p(?:(\d+)w)?(?:(\d+)d)?(?:t(?:(\d+)h)?(?:(\d+)m)?(?:(\d+)s)?)?$")
+        .expect("invalid iso duration regex")
+});
+/// Keywords that make `redact_log_details` hide everything after the error category.
+const SENSITIVE_LOG_HINTS: [&str; 8] = [
+    "token",
+    "authorization",
+    "bearer",
+    "oauth",
+    "client_secret",
+    "password",
+    "code=",
+    "set-cookie",
+];
+/// Comma-separated extra keywords from `EXTRA_SENSITIVE_LOG_HINTS`, e.g. custom field names.
+static EXTRA_SENSITIVE_LOG_HINTS: Lazy<Vec<String>> = Lazy::new(|| {
+    env::var("EXTRA_SENSITIVE_LOG_HINTS")
+        .map(|raw| parse_sensitive_log_hints(&raw))
+        .unwrap_or_default()
+});
+/// Whether `YTRACKER_FULL_SCROLL_IDS=1` asks for untruncated scroll ids in logs.
+static FULL_SCROLL_IDS: Lazy<bool> =
+    Lazy::new(|| env::var("YTRACKER_FULL_SCROLL_IDS").is_ok_and(|value| value == "1"));
 const DEFAULT_ISSUE_QUERY: &str = "Assignee: me() Resolution: empty()";
+/// Filter keys holding user logins where the `me()` shortcut is rewritten.
+const USER_FILTER_FIELDS: [&str; 4] = ["assignee", "reporter", "updatedBy", "createdBy"];
+/// Standard issue fields accepted as filter keys; anything else is passed through with a warning.
+const KNOWN_FILTER_KEYS: &[&str] = &[
+    "key",
+    "queue",
+    "summary",
+    "description",
+    "type",
+    "status",
+    "resolution",
+    "priority",
+    "assignee",
+    "author",
+    "reporter",
+    "createdBy",
+    "updatedBy",
+    "resolvedBy",
+    "followers",
+    "tags",
+    "components",
+    "project",
+    "sprint",
+    "boards",
+    "parent",
+    "epic",
+    "createdAt",
+    "updatedAt",
+    "resolvedAt",
+    "statusStartTime",
+    "start",
+    "end",
+    "deadline",
+    "dueDate",
+    "originalEstimation",
+    "estimation",
+    "spent",
+    "storyPoints",
+    "votes",
+];
 const TRAY_ID: &str = "YTracker";
 const MENU_STOP_ID: &str = "tray_stop_timer";
 const MENU_REFRESH_ID: &str = "tray_refresh";
 const MENU_RUNNING_LABEL_ID: &str = "tray_running_label";
 const MENU_IDLE_LABEL_ID: &str = "tray_idle_label";
 const MENU_NO_ISSUES_ID: &str = "tray_no_issues";
+const MENU_OVERDUE_LABEL_ID: &str = "tray_overdue_label";
 const MENU_MORE_ISSUES_ID: &str = "tray_more_issues";
 const MENU_START_SUBMENU_ID: &str = "tray_start_submenu";
 const ISSUE_MENU_PREFIX: &str = "tray_issue::";
+const QUEUE_MENU_PREFIX: &str = "tray_queue::";
+const RECENT_MENU_PREFIX: &str = "tray_recent::";
+const DONE_MENU_PREFIX: &str = "tray_done::";
+const ISSUE_SUBMENU_PREFIX: &str = "tray_issue_menu::";
+/// Destination status keys treated as "done" by the quick close action, in order of preference.
+const DONE_STATUS_KEYS: [&str; 3] = ["closed", "resolved", "done"];
+/// Resolution sent when closing an issue, since Tracker requires one for the `closed` status.
+const DEFAULT_DONE_RESOLUTION: &str = "fixed";
+const MENU_RECENT_SUBMENU_ID: &str = "tray_recent_submenu";
+const TRACKER_WEB_BASE_YANDEX360: &str = "https://tracker.yandex.ru";
+const TRACKER_WEB_BASE_CLOUD: &str = "https://tracker.yandex.cloud";
 const MAX_TRAY_ISSUES: usize = 12;
 const ISSUE_REFRESH_INTERVAL_SECS: u64 = 300;
 const ISSUE_SCROLL_PER_PAGE: u32 = 100;
 const ISSUE_SCROLL_TTL_MILLIS: u64 = 60_000;
+const SCROLL_CONTEXT_SWEEP_INTERVAL_SECS: u64 = 120;
+const FULLTEXT_MIN_CHARS: usize = 3;
+/// Error string returned to the frontend when Tracker rejects the stored token.
+const SESSION_EXPIRED_ERROR: &str = "session-expired";
+const WORKDAY_START_REMINDER_TEXT: &str = "Good morning — don't forget to start your timer!";
 const WORKDAY_MOTIVATION_PHRASES: [&str; 8] = [
     "Small progress is still progress — you've got this.",
     "A little more focus now will make tomorrow easier.",
@@ -99,6 +189,11 @@ struct TimerStoppedPayload {
     elapsed: u64,
 }
 
+#[derive(Debug, Serialize)]
+struct IssueClosedPayload {
+    issue_key: String,
+}
+
 #[derive(Debug, Serialize, Clone)]
 struct IssuePagePayload {
     issues: Vec<bridge::Issue>,
@@ -118,36 +213,106 @@ fn format_elapsed(elapsed: u64) -> String {
     }
 }
 
+/// Formats elapsed seconds with second-level resolution below one hour.
+fn format_elapsed_precise(elapsed: u64) -> String {
+    if elapsed < 60 {
+        format!("{}s", elapsed)
+    } else if elapsed < 3600 {
+        format!("{}m {:02}s", elapsed / 60, elapsed % 60)
+    } else {
+        format_elapsed(elapsed)
+    }
+}
+
 /// Parses local workday time in `HH:MM` format.
 fn parse_workday_time(value: &str) -> Option<NaiveTime> {
     NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
 }
 
+/// Parses an IANA workday timezone name; blank or unknown names mean system local time.
+fn parse_workday_timezone(value: Option<&str>) -> Option<Tz> {
+    value
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .and_then(|name| name.parse::<Tz>().ok())
+}
+
+/// Extracts the tracker client proxy settings from config.
+fn proxy_settings(config: &Config) -> ProxySettings {
+    ProxySettings {
+        proxy_url: config.proxy_url.clone(),
+        no_proxy: config.no_proxy.clone(),
+    }
+}
+
+/// Returns the in-memory config, falling back to disk before the cache is managed.
+fn cached_config<R: Runtime>(app: &tauri::AppHandle<R>) -> Config {
+    app.try_state::<ConfigCache>()
+        .map(|cache| cache.get())
+        .unwrap_or_else(|| normalize_config(ConfigManager::new().load()))
+}
+
+/// Converts a timestamp into the workday timezone, or system local time when unset.
+fn to_workday_time(value: DateTime<FixedOffset>, tz: Option<Tz>) -> DateTime<FixedOffset> {
+    match tz {
+        Some(tz) => value.with_timezone(&tz).fixed_offset(),
+        None => value.with_timezone(&Local).fixed_offset(),
+    }
+}
+
+/// Returns the current time in the workday timezone.
+fn workday_now(tz: Option<Tz>) -> DateTime<FixedOffset> {
+    to_workday_time(Utc::now().fixed_offset(), tz)
+}
+
 /// Returns current local day key used for same-day aggregation logic.
-fn current_local_day_key() -> String {
-    Local::now().format("%Y-%m-%d").to_string()
+fn current_local_day_key(tz: Option<Tz>) -> String {
+    workday_now(tz).format("%Y-%m-%d").to_string()
 }
 
-/// Parses Tracker datetime string into local timezone representation.
-fn parse_tracker_datetime(value: &str) -> Option<DateTime<Local>> {
+/// Parses Tracker datetime string into the workday timezone representation.
+fn parse_tracker_datetime(value: &str, tz: Option<Tz>) -> Option<DateTime<FixedOffset>> {
     DateTime::parse_from_rfc3339(value)
         .ok()
-        .map(|dt| dt.with_timezone(&Local))
-        .or_else(|| {
-            DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z")
-                .ok()
-                .map(|dt| dt.with_timezone(&Local))
-        })
+        .or_else(|| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z").ok())
+        .map(|dt| to_workday_time(dt, tz))
+}
+
+/// Returns whether the start-of-workday reminder should fire right now.
+///
+/// The reminder is only useful inside working hours, while nothing is being
+/// tracked and nothing has been logged yet today.
+fn should_remind_workday_start(
+    now: NaiveTime,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+    timer_active: bool,
+    logged_today_seconds: u64,
+) -> bool {
+    let Some(start) = start else {
+        return false;
+    };
+    now >= start
+        && end.map(|end| now < end).unwrap_or(true)
+        && !timer_active
+        && logged_today_seconds == 0
 }
 
 /// Picks a pseudo-random motivational phrase for workday notifications.
-fn motivational_phrase() -> &'static str {
+///
+/// User-configured phrases take precedence over the built-in list when present.
+fn select_motivational_phrase(config: &Config) -> String {
     let nanos = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map(|duration| duration.subsec_nanos() as usize)
         .unwrap_or(0);
-    let index = nanos % WORKDAY_MOTIVATION_PHRASES.len();
-    WORKDAY_MOTIVATION_PHRASES[index]
+    if config.custom_motivational_phrases.is_empty() {
+        let index = nanos % WORKDAY_MOTIVATION_PHRASES.len();
+        WORKDAY_MOTIVATION_PHRASES[index].to_string()
+    } else {
+        let index = nanos % config.custom_motivational_phrases.len();
+        config.custom_motivational_phrases[index].clone()
+    }
 }
 
 /// Collapses repeated whitespace to a single space.
@@ -169,6 +334,42 @@ fn truncate_text(value: &str, limit: usize) -> String {
     truncated
 }
 
+/// Like [`truncate_text`], but cuts at the last whitespace within the limit when there is one.
+fn truncate_text_at_word(value: &str, limit: usize) -> String {
+    let trimmed = value.trim();
+    if trimmed.chars().count() <= limit {
+        return trimmed.to_string();
+    }
+    if limit <= 1 {
+        return "…".to_string();
+    }
+    let prefix: String = trimmed.chars().take(limit - 1).collect();
+    let next_is_boundary = trimmed
+        .chars()
+        .nth(limit - 1)
+        .is_some_and(char::is_whitespace);
+    let mut truncated = if next_is_boundary {
+        prefix.trim_end().to_string()
+    } else {
+        match prefix.rfind(char::is_whitespace) {
+            Some(index) if !prefix[..index].trim_end().is_empty() => {
+                prefix[..index].trim_end().to_string()
+            }
+            _ => prefix,
+        }
+    };
+    truncated.push('…');
+    truncated
+}
+
+/// Splits a comma-separated keyword list into trimmed, lowercased, non-empty hints.
+fn parse_sensitive_log_hints(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(|hint| hint.trim().to_lowercase())
+        .filter(|hint| !hint.is_empty())
+        .collect()
+}
+
 /// Redacts potentially sensitive details from loggable error text.
 fn redact_log_details(value: &str) -> String {
     let collapsed = collapse_whitespace(value);
@@ -179,18 +380,11 @@ fn redact_log_details(value: &str) -> String {
         .filter(|segment| !segment.is_empty())
         .unwrap_or("error");
     let lowered = collapsed.to_lowercase();
-    let has_sensitive_hint = [
-        "token",
-        "authorization",
-        "bearer",
-        "oauth",
-        "client_secret",
-        "password",
-        "code=",
-        "set-cookie",
-    ]
-    .iter()
-    .any(|hint| lowered.contains(hint));
+    let has_sensitive_hint = SENSITIVE_LOG_HINTS
+        .iter()
+        .copied()
+        .chain(EXTRA_SENSITIVE_LOG_HINTS.iter().map(String::as_str))
+        .any(|hint| lowered.contains(hint));
 
     if has_sensitive_hint {
         return format!(
@@ -208,7 +402,7 @@ fn format_issue_label(issue: &bridge::Issue) -> String {
     if summary.is_empty() {
         issue.key.clone()
     } else {
-        format!("{}: {}", issue.key, truncate_text(&summary, 60))
+        format!("{}: {}", issue.key, truncate_text_at_word(&summary, 60))
     }
 }
 
@@ -225,15 +419,94 @@ fn format_running_label(state: &timer::TimerState) -> String {
         "Running: {} — {} ({})",
         key,
         summary,
-        format_elapsed(state.elapsed)
+        format_elapsed_precise(state.elapsed)
     )
 }
 
+/// Formats seconds as `Xh YYm` for tray tooltips.
+fn format_hours_minutes(seconds: u64) -> String {
+    format!("{}h {:02}m", seconds / 3600, (seconds % 3600) / 60)
+}
+
+/// Builds tray tooltip with today's logged total, the running timer, if any, and the
+/// number of cached issues.
+fn format_tray_tooltip(
+    timer_state: &timer::TimerState,
+    today_logged_seconds: u64,
+    issue_count: usize,
+) -> String {
+    let today = format_hours_minutes(today_logged_seconds);
+    let summary = match timer_state.issue_key.as_deref() {
+        Some(key) if timer_state.active => format!(
+            "Tracking: {} {} / Today: {}",
+            key,
+            format_hours_minutes(timer_state.elapsed),
+            today
+        ),
+        _ => format!("Today: {} logged", today),
+    };
+    match issue_count {
+        0 => summary,
+        1 => format!("{} / 1 issue", summary),
+        count => format!("{} / {} issues", summary, count),
+    }
+}
+
+/// Refreshes cached today-logged total used by tray tooltip and timer ticks.
+async fn refresh_today_logged_seconds(
+    app: &tauri::AppHandle,
+    issue_store: &IssueStore,
+) -> Result<(), String> {
+    let config = ConfigManager::new().load();
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+    let total =
+        fetch_today_logged_seconds_for_issues(app, &issue_store.snapshot(), workday_hours, tz)
+            .await?;
+    issue_store.set_today_logged_seconds(total);
+    Ok(())
+}
+
+/// Counts issues whose `YYYY-MM-DD` deadline falls before `today`.
+fn count_overdue_issues(issues: &[bridge::Issue], today: NaiveDate) -> usize {
+    issues
+        .iter()
+        .filter_map(|issue| issue.deadline.as_deref())
+        .filter_map(|deadline| NaiveDate::parse_from_str(deadline.get(..10)?, "%Y-%m-%d").ok())
+        .filter(|deadline| *deadline < today)
+        .count()
+}
+
+/// Counts overdue issues against today in the workday timezone, for the tray.
+fn count_tray_overdue_issues(issues: &[bridge::Issue], tz: Option<Tz>) -> usize {
+    count_overdue_issues(issues, workday_now(tz).date_naive())
+}
+
+/// Groups issues by queue prefix, keeping queues in order of first appearance.
+fn group_issues_by_queue(issues: &[bridge::Issue]) -> Vec<(&str, Vec<&bridge::Issue>)> {
+    let mut groups: Vec<(&str, Vec<&bridge::Issue>)> = Vec::new();
+    for issue in issues {
+        let queue = issue_queue_key(&issue.key);
+        match groups.iter_mut().find(|(key, _)| *key == queue) {
+            Some((_, members)) => members.push(issue),
+            None => groups.push((queue, vec![issue])),
+        }
+    }
+    groups
+}
+
 /// Creates deterministic tray menu item id for an issue key.
 fn issue_menu_id(issue_key: &str) -> String {
     format!("{}{}", ISSUE_MENU_PREFIX, issue_key)
 }
 
+/// Extracts issue key from tray "Start Timer" or "Recent" menu item ids.
+fn tray_menu_issue_key(menu_id: &str) -> Option<&str> {
+    menu_id
+        .strip_prefix(ISSUE_MENU_PREFIX)
+        .or_else(|| menu_id.strip_prefix(RECENT_MENU_PREFIX))
+}
+
 /// Shows a system notification when timer starts.
 fn notify_timer_started(app: &tauri::AppHandle, issue_key: &str, summary: Option<&str>) {
     let title = format!("Timer started: {}", issue_key);
@@ -257,6 +530,70 @@ fn notify_timer_stopped(app: &tauri::AppHandle, issue_key: &str, elapsed: u64) {
     }
 }
 
+/// Converts elapsed seconds into an exact duration input (`"1h 2m 5s"`), or `None` for zero.
+fn elapsed_to_duration_input(elapsed: u64) -> Option<String> {
+    if elapsed == 0 {
+        return None;
+    }
+    let parts = [
+        (elapsed / 3600, "h"),
+        (elapsed % 3600 / 60, "m"),
+        (elapsed % 60, "s"),
+    ];
+    let tokens: Vec<String> = parts
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+    Some(tokens.join(" "))
+}
+
+/// Applies the auto-log policy after a timer stop.
+///
+/// Returns `true` when a worklog submission was started in the background. When
+/// auto-logging is enabled but the elapsed time is under `auto_log_min_seconds`,
+/// emits `worklog-skipped-too-short` so the frontend can explain why nothing was logged.
+fn auto_log_stopped_timer(app: &tauri::AppHandle, issue_key: &str, elapsed: u64) -> bool {
+    let config = ConfigManager::new().load();
+    if !config.auto_log_on_timer_stop {
+        return false;
+    }
+
+    if elapsed < config.auto_log_min_seconds {
+        let payload = TimerStoppedPayload {
+            issue_key: issue_key.to_string(),
+            elapsed,
+        };
+        if let Err(err) = app.emit("worklog-skipped-too-short", &payload) {
+            warn!("Failed to emit worklog-skipped-too-short event: {}", err);
+        }
+        return false;
+    }
+    let Some(duration) = elapsed_to_duration_input(elapsed) else {
+        return false;
+    };
+
+    let secrets = match secrets_from_app(app) {
+        Ok(secrets) => secrets,
+        Err(err) => {
+            warn!("Automatic worklog skipped: {}", err);
+            return false;
+        }
+    };
+
+    let issue_key = issue_key.to_string();
+    tauri::async_runtime::spawn(async move {
+        match log_work_native(secrets, &issue_key, &duration, "").await {
+            Ok(()) => info!("Automatically logged {} for {}", duration, issue_key),
+            Err(err) => {
+                warn!("Automatic worklog submission failed");
+                debug!("Automatic worklog details: {}", redact_log_details(&err));
+            }
+        }
+    });
+    true
+}
+
 /// Emits frontend event indicating timer was stopped from any surface.
 fn emit_timer_stopped_event(app: &tauri::AppHandle, issue_key: &str, elapsed: u64) {
     let payload = TimerStoppedPayload {
@@ -269,9 +606,16 @@ fn emit_timer_stopped_event(app: &tauri::AppHandle, issue_key: &str, elapsed: u6
     }
 }
 
+/// Returns timer snapshot enriched with the cached today-logged total.
+fn timer_snapshot(timer: &Timer, issue_store: &IssueStore) -> timer::TimerState {
+    let mut snapshot = timer.get_state();
+    snapshot.total_today_seconds = issue_store.today_logged_seconds();
+    snapshot
+}
+
 /// Broadcasts timer snapshot and updates tray menu to reflect latest state.
 fn broadcast_timer_state(app: &tauri::AppHandle, timer: &Arc<Timer>, issue_store: &IssueStore) {
-    let snapshot = timer.get_state();
+    let snapshot = timer_snapshot(timer, issue_store);
     if let Err(err) = app.emit("timer-tick", &snapshot) {
         warn!("Failed to emit timer tick: {}", err);
     }
@@ -288,6 +632,7 @@ async fn refresh_issue_cache(
     query: Option<String>,
 ) -> Result<Vec<bridge::Issue>, String> {
     debug!("Refreshing issue cache");
+    let is_default_query = query.is_none();
     let params = if let Some(q) = query {
         IssueSearchParams::new(Some(q), None)
     } else {
@@ -309,14 +654,33 @@ async fn refresh_issue_cache(
     if let Err(err) = update_tray_menu(&app, &issues, &state) {
         warn!("Failed to update tray state: {}", err);
     }
+    if is_default_query {
+        emit_issue_cache_updated(&app, &issues);
+    }
     Ok(issues)
 }
 
+/// Emits `issue-cache-updated` with the first page of the refreshed cache as a complete list.
+fn emit_issue_cache_updated(app: &tauri::AppHandle, issues: &[bridge::Issue]) {
+    let page_len = issues.len().min(ISSUE_SCROLL_PER_PAGE as usize);
+    let payload = IssuePagePayload {
+        issues: issues[..page_len].to_vec(),
+        next_scroll_id: None,
+        total_count: Some(issues.len() as u64),
+        has_more: false,
+    };
+    if let Err(err) = app.emit("issue-cache-updated", &payload) {
+        warn!("Failed to emit issue-cache-updated event: {}", err);
+    }
+}
+
 /// Builds tray menu tree for timer controls and recent issues.
 fn build_tray_menu<R: Runtime>(
     app: &tauri::AppHandle<R>,
     issues: &[bridge::Issue],
     timer_state: &timer::TimerState,
+    overdue_count: usize,
+    group_by_queue: bool,
 ) -> tauri::Result<Menu<R>> {
     let menu = Menu::new(app)?;
 
@@ -338,6 +702,17 @@ fn build_tray_menu<R: Runtime>(
         menu.append(&idle_item)?;
     }
 
+    if overdue_count > 0 {
+        let overdue_item = MenuItem::with_id(
+            app,
+            MENU_OVERDUE_LABEL_ID,
+            format!("⚠ {} overdue", overdue_count),
+            false,
+            None::<&str>,
+        )?;
+        menu.append(&overdue_item)?;
+    }
+
     menu.append(&PredefinedMenuItem::separator(app)?)?;
 
     if issues.is_empty() {
@@ -350,18 +725,66 @@ fn build_tray_menu<R: Runtime>(
         )?;
         menu.append(&placeholder)?;
     } else {
-        let start_submenu = Submenu::with_id(app, MENU_START_SUBMENU_ID, "Start Timer", true)?;
+        let recent_issues = app
+            .try_state::<IssueStore>()
+            .map(|store| store.recent_issues())
+            .unwrap_or_default();
+        if !recent_issues.is_empty() {
+            let recent_submenu = Submenu::with_id(app, MENU_RECENT_SUBMENU_ID, "Recent", true)?;
+            for issue in &recent_issues {
+                let enabled = timer_state.issue_key.as_deref() != Some(&issue.key);
+                let entry = MenuItem::with_id(
+                    app,
+                    format!("{}{}", RECENT_MENU_PREFIX, issue.key),
+                    format_issue_label(issue),
+                    enabled,
+                    None::<&str>,
+                )?;
+                recent_submenu.append(&entry)?;
+            }
+            menu.append(&recent_submenu)?;
+        }
 
-        for issue in issues.iter().take(MAX_TRAY_ISSUES) {
+        let start_submenu = Submenu::with_id(app, MENU_START_SUBMENU_ID, "Issues", true)?;
+        let visible_issues = &issues[..issues.len().min(MAX_TRAY_ISSUES)];
+        let issue_entry = |issue: &bridge::Issue| -> tauri::Result<Submenu<R>> {
             let enabled = timer_state.issue_key.as_deref() != Some(&issue.key);
-            let entry = MenuItem::with_id(
+            let start_item = MenuItem::with_id(
                 app,
                 issue_menu_id(&issue.key),
-                format_issue_label(issue),
+                "Start Timer",
                 enabled,
                 None::<&str>,
             )?;
-            start_submenu.append(&entry)?;
+            let done_item = MenuItem::with_id(
+                app,
+                format!("{}{}", DONE_MENU_PREFIX, issue.key),
+                "Mark as Done",
+                true,
+                None::<&str>,
+            )?;
+            Submenu::with_id_and_items(
+                app,
+                format!("{}{}", ISSUE_SUBMENU_PREFIX, issue.key),
+                format_issue_label(issue),
+                true,
+                &[&start_item, &done_item],
+            )
+        };
+
+        if group_by_queue {
+            for (queue, members) in group_issues_by_queue(visible_issues) {
+                let queue_submenu =
+                    Submenu::with_id(app, format!("{}{}", QUEUE_MENU_PREFIX, queue), queue, true)?;
+                for issue in members {
+                    queue_submenu.append(&issue_entry(issue)?)?;
+                }
+                start_submenu.append(&queue_submenu)?;
+            }
+        } else {
+            for issue in visible_issues {
+                start_submenu.append(&issue_entry(issue)?)?;
+            }
         }
 
         if issues.len() > MAX_TRAY_ISSUES {
@@ -400,19 +823,41 @@ fn update_tray_menu<R: Runtime>(
     timer_state: &timer::TimerState,
 ) -> tauri::Result<()> {
     if let Some(tray) = app.tray_by_id(TRAY_ID) {
-        let menu = build_tray_menu(app, issues, timer_state)?;
+        let config = cached_config(app);
+        let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+        let overdue_count = count_tray_overdue_issues(issues, tz);
+        let menu = build_tray_menu(
+            app,
+            issues,
+            timer_state,
+            overdue_count,
+            config.tray_group_by_queue,
+        )?;
         tray.set_menu(Some(menu))?;
 
-        let title = if timer_state.active {
+        let mut title = if timer_state.active {
             let key = timer_state.issue_key.as_deref().unwrap_or("Timer");
             format!("YT: {} ({})", key, format_elapsed(timer_state.elapsed))
         } else {
             "YTracker".to_string()
         };
 
+        if overdue_count > 0 {
+            title.push_str(&format!(" (!{})", overdue_count));
+        }
+
         if let Err(err) = tray.set_title(Some(&title)) {
             debug!("Failed to set tray title: {}", err);
         }
+
+        let (today_logged, issue_count) = app
+            .try_state::<IssueStore>()
+            .map(|store| (store.today_logged_seconds(), store.count_total()))
+            .unwrap_or_default();
+        let tooltip = format_tray_tooltip(timer_state, today_logged, issue_count);
+        if let Err(err) = tray.set_tooltip(Some(&tooltip)) {
+            debug!("Failed to set tray tooltip: {}", err);
+        }
     }
 
     Ok(())
@@ -444,7 +889,7 @@ async fn get_current_user(
     get_current_user_native(&secrets).await
 }
 
-/// Clears session/token state and resets timer/issue runtime state.
+/// Clears session/token state, revokes the token server-side and resets timer/issue runtime state.
 #[tauri::command]
 async fn logout(
     app: tauri::AppHandle,
@@ -452,6 +897,7 @@ async fn logout(
     issue_store: tauri::State<'_, IssueStore>,
     timer: tauri::State<'_, Arc<Timer>>,
 ) -> Result<(), String> {
+    let session = secrets.get_session().ok().flatten();
     secrets
         .clear_session()
         .map_err(|err| format!("Failed to clear session: {}", err))?;
@@ -460,18 +906,50 @@ async fn logout(
     issue_store.set(Vec::new());
     broadcast_timer_state(&app, &timer, issue_store.inner());
 
+    // The local session is already gone, so revocation failures are only logged.
+    let credentials = secrets.get_credentials().ok().flatten();
+    if let (Some(session), Some(credentials)) = (session, credentials) {
+        if let Err(err) = auth::revoke_token(
+            &session.token,
+            &credentials.client_id,
+            &credentials.client_secret,
+        )
+        .await
+        {
+            warn!("Failed to revoke access token");
+            debug!(
+                "Token revocation details: {}",
+                redact_log_details(&err.to_string())
+            );
+        }
+    }
+
     Ok(())
 }
 
 async fn get_current_user_native(secrets: &SecretsManager) -> Result<bridge::UserProfile, String> {
     let client = build_tracker_client(secrets)?;
-    let profile = client.get_myself().await.map_err(|err| err.to_string())?;
+    let profile = get_myself_cached(&client, secrets).await?;
     Ok(convert_user_profile(profile))
 }
 
+/// Returns the signed-in user's profile, calling `myself` at most once per session.
+async fn get_myself_cached(
+    client: &TrackerClient,
+    secrets: &SecretsManager,
+) -> Result<NativeUserProfile, String> {
+    if let Some(profile) = secrets.get_cached_user_profile() {
+        return Ok(profile);
+    }
+    let profile = client.get_myself().await.map_err(|err| err.to_string())?;
+    secrets.set_cached_user_profile(profile.clone());
+    Ok(profile)
+}
+
 fn convert_user_profile(profile: NativeUserProfile) -> bridge::UserProfile {
     let avatar_url = profile.avatar();
     bridge::UserProfile {
+        uid: profile.uid,
         display: profile.display,
         login: profile.login,
         email: profile.email,
@@ -493,23 +971,56 @@ fn parse_org_type(value: &str) -> OrgType {
     }
 }
 
+/// Returns the queue prefix of an issue key (`FOO` for `FOO-123`).
+fn issue_queue_key(issue_key: &str) -> &str {
+    issue_key
+        .split_once('-')
+        .map(|(queue, _)| queue)
+        .unwrap_or(issue_key)
+}
+
+/// Builds the Tracker web UI URL for an issue, picking the host by org type.
+///
+/// The queue segment is derived from the issue key prefix (`QUEUE-123`).
+fn get_issue_url(issue_key: &str, org_type: &OrgType) -> String {
+    let key = issue_key.trim();
+    let queue = issue_queue_key(key);
+    let base = match org_type {
+        OrgType::Yandex360 => TRACKER_WEB_BASE_YANDEX360,
+        OrgType::Cloud => TRACKER_WEB_BASE_CLOUD,
+    };
+    format!("{}/{}/{}", base, queue, key)
+}
+
 fn build_tracker_client(secrets: &SecretsManager) -> Result<TrackerClient, String> {
     let session = secrets
         .get_session()
         .map_err(|e| format!("Failed to load stored token: {}", e))?
         .ok_or_else(|| "Not authenticated. Sign in again to continue.".to_string())?;
-    tracker_client_from_session(&session, secrets.get_rate_limiter())
+    tracker_client_from_session(
+        &session,
+        secrets.get_rate_limiter(),
+        secrets.get_proxy_settings(),
+    )
+    .map(|client| client.with_connectivity_cache(secrets.get_connectivity_state()))
 }
 
 fn tracker_client_from_session(
     session: &SessionToken,
     limiter: RateLimiter,
+    proxy: ProxySettings,
 ) -> Result<TrackerClient, String> {
     let org_type = parse_org_type(&session.org_type);
     let mut config = TrackerConfig::new(session.token.clone(), org_type);
     if let Some(org_id) = &session.org_id {
         config = config.with_org_id(org_id.clone());
     }
+    if let Some(proxy_url) = proxy.proxy_url {
+        config = config.with_proxy(proxy_url);
+    }
+    if let Some(no_proxy) = proxy.no_proxy {
+        config = config.with_no_proxy(no_proxy);
+    }
     TrackerClient::new_with_limiter(config, limiter).map_err(|err| err.to_string())
 }
 
@@ -537,6 +1048,14 @@ fn convert_issues_native(issues: Vec<NativeIssue>) -> Vec<bridge::Issue> {
         .collect()
 }
 
+fn convert_linked_issue_native(link: NativeLinkedIssue, workday_hours: u64) -> bridge::LinkedIssue {
+    bridge::LinkedIssue {
+        relation_type: link.relation_type,
+        direction: link.direction,
+        issue: convert_issue_native(link.issue, workday_hours),
+    }
+}
+
 fn convert_issue_native(issue: NativeIssue, workday_hours: u64) -> bridge::Issue {
     let (status_key, status_display) = coerce_field_ref(issue.status.as_ref());
     let (priority_key, priority_display) = coerce_field_ref(issue.priority.as_ref());
@@ -553,6 +1072,31 @@ fn convert_issue_native(issue: NativeIssue, workday_hours: u64) -> bridge::Issue
 
     let tags = issue.tags.clone().unwrap_or_default();
 
+    let components = issue
+        .components
+        .iter()
+        .filter_map(|field| {
+            field
+                .display_value()
+                .as_ref()
+                .and_then(coerce_display_value_array)
+                .or_else(|| field.key())
+        })
+        .collect();
+
+    let parent_key = issue
+        .parent
+        .as_ref()
+        .and_then(|field| field.key())
+        .filter(|key| !key.trim().is_empty());
+    let parent_summary = issue
+        .parent
+        .as_ref()
+        .and_then(|field| field.display_value())
+        .as_ref()
+        .and_then(coerce_display_value)
+        .filter(|summary| Some(summary) != parent_key.as_ref());
+
     let followers = issue
         .followers
         .as_ref()
@@ -581,6 +1125,7 @@ fn convert_issue_native(issue: NativeIssue, workday_hours: u64) -> bridge::Issue
         issue_type,
         assignee,
         tags,
+        components,
         followers,
         tracked_seconds: issue
             .spent
@@ -592,6 +1137,10 @@ fn convert_issue_native(issue: NativeIssue, workday_hours: u64) -> bridge::Issue
                     .as_ref()
                     .and_then(|value| parse_duration_value_to_seconds(value, workday_hours))
             }),
+        parent_key,
+        parent_summary,
+        deadline: normalize_owned_string(issue.deadline),
+        votes: issue.votes,
     }
 }
 
@@ -623,23 +1172,45 @@ async fn fetch_issues_native(
     let secrets = secrets_from_app(app)?;
     let client = build_tracker_client(&secrets)?;
     let mut resolved_params = params.clone();
-    resolve_filter_shortcuts(&mut resolved_params, &client).await?;
-    let response = client
-        .search_issues(&resolved_params, None)
-        .await
-        .map_err(|err| err.to_string())?;
+    resolve_filter_shortcuts(&mut resolved_params, &client, &secrets).await?;
+    let response = handle_auth_error(app, client.search_issues(&resolved_params, None).await)?;
     Ok(convert_issues_native(response))
 }
 
+async fn fetch_issue_count_native(
+    app: &tauri::AppHandle,
+    params: &IssueSearchParams,
+) -> Result<u64, String> {
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    let mut resolved_params = params.clone();
+    resolve_filter_shortcuts(&mut resolved_params, &client, &secrets).await?;
+    handle_auth_error(app, client.get_issue_count(&resolved_params).await)
+}
+
 async fn fetch_issue_page_native(
     app: &tauri::AppHandle,
     params: &IssueSearchParams,
     scroll_id: Option<&str>,
 ) -> Result<IssuePagePayload, String> {
+    if let (Some(id), Some(prefetched)) = (
+        scroll_id,
+        app.try_state::<PrefetchedPages<IssuePagePayload>>(),
+    ) {
+        let ttl = std::time::Duration::from_millis(ISSUE_SCROLL_TTL_MILLIS);
+        if let Some(page) = prefetched.take(id, ttl) {
+            debug!(
+                "tracker:get_issues served prefetched page scroll={}",
+                describe_scroll_id(Some(id))
+            );
+            return Ok(page);
+        }
+    }
+
     let secrets = secrets_from_app(app)?;
     let client = build_tracker_client(&secrets)?;
     let mut resolved_params = params.clone();
-    resolve_filter_shortcuts(&mut resolved_params, &client).await?;
+    resolve_filter_shortcuts(&mut resolved_params, &client, &secrets).await?;
     let response = client
         .search_issues_scroll(
             &resolved_params,
@@ -648,19 +1219,55 @@ async fn fetch_issue_page_native(
             ScrollType::Sorted,
             Some(ISSUE_SCROLL_TTL_MILLIS),
         )
-        .await
-        .map_err(|err| err.to_string())?;
+        .await;
+    let response = handle_auth_error(app, response)?;
+
+    Ok(issue_page_payload(app, response))
+}
+
+/// Fetches the page behind `scroll_id` and keeps it until `get_issues` asks for that scroll id.
+async fn prefetch_issue_page_native(
+    app: &tauri::AppHandle,
+    params: &IssueSearchParams,
+    scroll_id: &str,
+) -> Result<(), String> {
+    let prefetched = app.state::<PrefetchedPages<IssuePagePayload>>();
+    if prefetched.contains(scroll_id) {
+        return Ok(());
+    }
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    let mut resolved_params = params.clone();
+    resolve_filter_shortcuts(&mut resolved_params, &client, &secrets).await?;
+    let response = client
+        .prefetch_scroll_page(&resolved_params, scroll_id, ISSUE_SCROLL_PER_PAGE)
+        .await;
+    let response = handle_auth_error(app, response)?;
+    prefetched.insert(scroll_id, issue_page_payload(app, response));
+    Ok(())
+}
 
+/// Converts a scroll page for the bridge and starts tracking its next scroll id.
+fn issue_page_payload(
+    app: &tauri::AppHandle,
+    response: ScrollPage<NativeIssue>,
+) -> IssuePagePayload {
     let issues = convert_issues_native(response.items);
     let next_scroll_id = response.scroll_id;
     let has_more = next_scroll_id.is_some();
+    if let (Some(id), Some(registry)) = (
+        next_scroll_id.as_deref(),
+        app.try_state::<ScrollContextRegistry>(),
+    ) {
+        registry.register(id);
+    }
 
-    Ok(IssuePagePayload {
+    IssuePagePayload {
         issues,
         next_scroll_id,
         total_count: response.total_count,
         has_more,
-    })
+    }
 }
 
 async fn fetch_comments_native(
@@ -675,6 +1282,31 @@ async fn fetch_comments_native(
     Ok(convert_comments_native(comments))
 }
 
+async fn fetch_comment_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    comment_id: &str,
+) -> Result<bridge::Comment, String> {
+    let client = build_tracker_client(&secrets)?;
+    let comment = client
+        .get_comment(issue_key, comment_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_comment_native(comment))
+}
+
+async fn fetch_changelog_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<bridge::IssueHistory, String> {
+    let client = build_tracker_client(&secrets)?;
+    let entries = client
+        .get_issue_changelog(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_changelog_native(entries))
+}
+
 async fn fetch_attachments_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -687,13 +1319,16 @@ async fn fetch_attachments_native(
     Ok(convert_attachments_native(attachments))
 }
 
+/// Field projection for the issue detail pane: the list fields plus reporter, queue, estimate and sprint.
+const ISSUE_DETAIL_FIELDS: &str = "key,summary,description,status,priority,type,assignee,reporter,queue,tags,components,followers,parent,deadline,votes,originalEstimation,sprint,spent,timeSpent";
+
 async fn fetch_issue_detail_native(
     secrets: SecretsManager,
     issue_key: &str,
 ) -> Result<bridge::Issue, String> {
     let client = build_tracker_client(&secrets)?;
     let issue = client
-        .get_issue(issue_key)
+        .get_issue_with_fields(issue_key, ISSUE_DETAIL_FIELDS)
         .await
         .map_err(|err| err.to_string())?;
     let config = ConfigManager::new().load();
@@ -701,6 +1336,23 @@ async fn fetch_issue_detail_native(
     Ok(convert_issue_native(issue, workday_hours))
 }
 
+async fn fetch_linked_issues_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<Vec<bridge::LinkedIssue>, String> {
+    let client = build_tracker_client(&secrets)?;
+    let linked = client
+        .get_issue_linked_issues(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    let config = ConfigManager::new().load();
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    Ok(linked
+        .into_iter()
+        .map(|link| convert_linked_issue_native(link, workday_hours))
+        .collect())
+}
+
 async fn fetch_worklogs_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -715,48 +1367,223 @@ async fn fetch_worklogs_native(
     Ok(convert_worklogs_native(entries, workday_hours))
 }
 
-// ─── Checklist helpers ───────────────────────────────────────────────
-
-fn checklist_item_id_string(value: &serde_json::Value) -> String {
-    match value {
-        serde_json::Value::String(s) => s.trim().to_string(),
-        serde_json::Value::Number(n) => n.to_string(),
-        other => other.to_string(),
-    }
-}
-
-fn convert_checklist_items_native(items: Vec<NativeChecklistItem>) -> Vec<bridge::ChecklistItem> {
-    items
-        .into_iter()
-        .map(|item| bridge::ChecklistItem {
-            id: checklist_item_id_string(&item.id),
-            text: item.text.unwrap_or_default(),
-            checked: item.checked.unwrap_or(false),
-            assignee: item
-                .assignee
-                .as_ref()
-                .and_then(|a| a.display.clone().or_else(|| a.login.clone())),
-            deadline: item.deadline.as_ref().and_then(|d| d.date.clone()),
-            deadline_type: item.deadline.as_ref().and_then(|d| d.deadline_type.clone()),
-            is_exceeded: item.deadline.as_ref().and_then(|d| d.is_exceeded),
-            item_type: item.checklist_item_type,
-        })
-        .collect()
-}
-
-async fn fetch_checklist_native(
+/// Loads an issue's worklogs between two inclusive `YYYY-MM-DD` dates in the workday timezone.
+async fn fetch_worklogs_in_range_native(
     secrets: SecretsManager,
     issue_key: &str,
-) -> Result<Vec<bridge::ChecklistItem>, String> {
+    from_date: &str,
+    to_date: &str,
+) -> Result<Vec<bridge::WorklogEntry>, String> {
+    let from = parse_date_bound(from_date, "start")?;
+    let to = parse_date_bound(to_date, "end")?;
+    if from > to {
+        return Err("Start date must not be after end date".to_string());
+    }
+    let config = ConfigManager::new().load();
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+    let (range_start, _) = local_day_bounds(from, tz)?;
+    let (_, range_end) = local_day_bounds(to, tz)?;
+
     let client = build_tracker_client(&secrets)?;
-    let items = client
-        .get_checklist(issue_key)
+    let entries = client
+        .get_issue_worklogs_in_range(
+            issue_key,
+            range_start.with_timezone(&Utc),
+            range_end.with_timezone(&Utc),
+        )
         .await
         .map_err(|err| err.to_string())?;
-    Ok(convert_checklist_items_native(items))
+    Ok(convert_worklogs_native(entries, workday_hours))
 }
 
-async fn add_checklist_item_native(
+// ─── Worklog export helpers ──────────────────────────────────────────
+
+const WORKLOG_CSV_HEADER: &str = "issue_key,date,duration_seconds,author,comment";
+
+/// Quotes a CSV field when it contains separators, quotes or line breaks.
+fn csv_escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parses an inclusive `YYYY-MM-DD` date bound supplied by the frontend.
+fn parse_date_bound(value: &str, label: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid {} date '{}', expected YYYY-MM-DD", label, value.trim()))
+}
+
+async fn export_worklogs_csv_native(
+    secrets: SecretsManager,
+    issue_keys: &[String],
+    date_from: &str,
+    date_to: &str,
+    dest_path: &str,
+) -> Result<usize, String> {
+    let from = parse_date_bound(date_from, "start")?;
+    let to = parse_date_bound(date_to, "end")?;
+    if from > to {
+        return Err("Start date must not be after end date".to_string());
+    }
+    let resolved_path = resolve_download_destination(dest_path)?;
+
+    let client = build_tracker_client(&secrets)?;
+    let config = ConfigManager::new().load();
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut csv = String::from(WORKLOG_CSV_HEADER);
+    csv.push('\n');
+    let mut rows = 0usize;
+
+    for issue_key in issue_keys.iter().map(|key| key.trim()) {
+        if issue_key.is_empty() || !seen.insert(issue_key) {
+            continue;
+        }
+
+        let entries = client
+            .get_issue_worklogs(issue_key)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        for entry in convert_worklogs_native(entries, workday_hours) {
+            let in_range = parse_tracker_datetime(&entry.date, tz)
+                .map(|date| {
+                    let day = date.date_naive();
+                    day >= from && day <= to
+                })
+                .unwrap_or(false);
+            if !in_range {
+                continue;
+            }
+
+            let fields = [
+                issue_key.to_string(),
+                entry.date,
+                entry.duration_seconds.to_string(),
+                entry.author,
+                entry.comment,
+            ];
+            let row = fields
+                .iter()
+                .map(|field| csv_escape_field(field))
+                .collect::<Vec<_>>()
+                .join(",");
+            csv.push_str(&row);
+            csv.push('\n');
+            rows += 1;
+        }
+    }
+
+    if let Some(parent) = resolved_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|err| err.to_string())?;
+        }
+    }
+
+    async_fs::write(&resolved_path, csv)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(rows)
+}
+
+/// Walks every page of an issue search and writes the issues to a JSON array file.
+///
+/// Returns the number of exported issues.
+async fn export_all_issues_json_native(
+    app: &tauri::AppHandle,
+    params: &IssueSearchParams,
+    dest_path: &str,
+) -> Result<usize, String> {
+    let resolved_path = resolve_download_destination(dest_path)?;
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    let mut resolved_params = params.clone();
+    resolve_filter_shortcuts(&mut resolved_params, &client, &secrets).await?;
+    let workday_hours = sanitize_workday_hours(ConfigManager::new().load().workday_hours);
+
+    let mut issues = Vec::new();
+    let mut stream = std::pin::pin!(client.search_issues_all(&resolved_params));
+    while let Some(issue) = stream.next().await {
+        let issue = handle_auth_error(app, issue)?;
+        issues.push(convert_issue_native(issue, workday_hours));
+    }
+    let json = serde_json::to_string_pretty(&issues).map_err(|err| err.to_string())?;
+
+    if let Some(parent) = resolved_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|err| err.to_string())?;
+        }
+    }
+
+    async_fs::write(&resolved_path, json)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(issues.len())
+}
+
+// ─── Checklist helpers ───────────────────────────────────────────────
+
+fn checklist_item_id_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn convert_checklist_items_native(items: Vec<NativeChecklistItem>) -> Vec<bridge::ChecklistItem> {
+    items
+        .into_iter()
+        .map(|item| bridge::ChecklistItem {
+            id: checklist_item_id_string(&item.id),
+            text: item.text.unwrap_or_default(),
+            checked: item.checked.unwrap_or(false),
+            assignee: item
+                .assignee
+                .as_ref()
+                .and_then(|a| a.display.clone().or_else(|| a.login.clone())),
+            deadline: item.deadline.as_ref().and_then(|d| d.date.clone()),
+            deadline_type: item.deadline.as_ref().and_then(|d| d.deadline_type.clone()),
+            is_exceeded: item.deadline.as_ref().and_then(|d| d.is_exceeded),
+            item_type: item.checklist_item_type,
+        })
+        .collect()
+}
+
+/// Summarizes checklist completion; `percent` is rounded down so it only reaches 100 when every item is checked.
+fn checklist_progress(items: &[bridge::ChecklistItem]) -> bridge::ChecklistProgress {
+    let total = items.len();
+    let checked = items.iter().filter(|item| item.checked).count();
+    let percent = (checked * 100).checked_div(total).unwrap_or(0) as u8;
+    bridge::ChecklistProgress {
+        total,
+        checked,
+        percent,
+    }
+}
+
+async fn fetch_checklist_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<Vec<bridge::ChecklistItem>, String> {
+    let client = build_tracker_client(&secrets)?;
+    let items = client
+        .get_checklist(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_checklist_items_native(items))
+}
+
+async fn add_checklist_item_native(
     secrets: SecretsManager,
     issue_key: &str,
     payload: bridge::ChecklistItemCreatePayload,
@@ -803,6 +1630,97 @@ async fn edit_checklist_item_native(
     Ok(())
 }
 
+/// Sets `checked` on several checklist items one by one through the shared rate limiter.
+///
+/// Every item is attempted; the error lists the ids that failed.
+async fn set_checklist_items_checked_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    item_ids: &[String],
+    checked: bool,
+) -> Result<(), String> {
+    let item_ids: Vec<&str> = item_ids
+        .iter()
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if item_ids.is_empty() {
+        return Err("At least one checklist item id is required".to_string());
+    }
+
+    let mut failed = Vec::new();
+    for item_id in item_ids {
+        let update = bridge::ChecklistItemUpdatePayload {
+            text: None,
+            checked: Some(checked),
+            assignee: None,
+            deadline: None,
+            deadline_type: None,
+        };
+        if let Err(err) =
+            edit_checklist_item_native(secrets.clone(), issue_key, item_id, update).await
+        {
+            warn!(
+                "Failed to update checklist item {} in {}: {}",
+                item_id,
+                issue_key,
+                err
+            );
+            failed.push(item_id);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Failed to update checklist items: {}",
+            failed.join(", ")
+        ))
+    }
+}
+
+/// Re-creates a checklist item with the same text, assignee and deadline, always unchecked.
+async fn duplicate_checklist_item_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    item_id: &str,
+) -> Result<(), String> {
+    let client = build_tracker_client(&secrets)?;
+    let items = client
+        .get_checklist(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    let item = items
+        .into_iter()
+        .find(|item| checklist_item_id_string(&item.id) == item_id.trim())
+        .ok_or_else(|| format!("Checklist item {} not found in {}", item_id, issue_key))?;
+
+    let assignee = item.assignee.as_ref().and_then(|assignee| {
+        assignee
+            .login
+            .clone()
+            .or_else(|| assignee.id.as_ref().map(checklist_item_id_string))
+    });
+    let deadline = item.deadline.as_ref().and_then(|deadline| {
+        deadline.date.clone().map(|date| ChecklistDeadlineInput {
+            date,
+            deadline_type: deadline.deadline_type.clone(),
+        })
+    });
+    let create = ChecklistItemCreate {
+        text: item.text.unwrap_or_default(),
+        checked: Some(false),
+        assignee,
+        deadline,
+    };
+    client
+        .add_checklist_item(issue_key, &create)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(())
+}
+
 async fn delete_checklist_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -826,35 +1744,51 @@ async fn delete_checklist_item_native(
         .map_err(|err| err.to_string())
 }
 
+/// Returns workday-timezone start of `day` and start of the following day.
+fn local_day_bounds(
+    day: NaiveDate,
+    tz: Option<Tz>,
+) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>), String> {
+    let start = day
+        .and_hms_opt(0, 0, 0)
+        .and_then(|naive| match tz {
+            Some(tz) => naive
+                .and_local_timezone(tz)
+                .single()
+                .map(|dt| dt.fixed_offset()),
+            None => naive
+                .and_local_timezone(Local)
+                .single()
+                .map(|dt| dt.fixed_offset()),
+        })
+        .ok_or_else(|| "Failed to resolve local day start".to_string())?;
+    Ok((start, start + Duration::days(1)))
+}
+
 async fn fetch_today_logged_seconds_for_issues(
     app: &tauri::AppHandle,
     issues: &[bridge::Issue],
     workday_hours: u64,
+    tz: Option<Tz>,
 ) -> Result<u64, String> {
     let issue_keys: Vec<String> = issues.iter().map(|issue| issue.key.clone()).collect();
-    fetch_today_logged_seconds_for_issue_keys(app, &issue_keys, workday_hours).await
+    fetch_today_logged_seconds_for_issue_keys(app, &issue_keys, workday_hours, tz).await
 }
 
 async fn fetch_today_logged_seconds_for_issue_keys(
     app: &tauri::AppHandle,
     issue_keys: &[String],
     workday_hours: u64,
+    tz: Option<Tz>,
 ) -> Result<u64, String> {
     let secrets = secrets_from_app(app)?;
     let client = build_tracker_client(&secrets)?;
-    let today_key = current_local_day_key();
-    let now_local = Local::now();
-    let start_of_today = now_local
-        .date_naive()
-        .and_hms_opt(0, 0, 0)
-        .and_then(|naive| naive.and_local_timezone(Local).single())
-        .ok_or_else(|| "Failed to resolve local day start".to_string())?;
-    let start_of_tomorrow = start_of_today + Duration::days(1);
+    let today_key = current_local_day_key(tz);
+    let (start_of_today, start_of_tomorrow) = local_day_bounds(workday_now(tz).date_naive(), tz)?;
     let created_from = start_of_today.to_rfc3339();
     let created_to = start_of_tomorrow.to_rfc3339();
 
-    let mut current_login: Option<String> = None;
-    let created_by = ensure_current_login(&client, &mut current_login).await.ok();
+    let created_by = ensure_current_login(&client, &secrets).await.ok();
 
     let entries = client
         .get_worklogs_by_params(
@@ -893,7 +1827,7 @@ async fn fetch_today_logged_seconds_for_issue_keys(
             .or(entry.created_at.as_deref())
             .unwrap_or("");
 
-        let is_today = parse_tracker_datetime(date_value)
+        let is_today = parse_tracker_datetime(date_value, tz)
             .map(|date| date.format("%Y-%m-%d").to_string() == today_key)
             .unwrap_or(false);
 
@@ -912,6 +1846,185 @@ async fn fetch_today_logged_seconds_for_issue_keys(
     Ok(total)
 }
 
+async fn fetch_daily_worklog_breakdown(
+    app: &tauri::AppHandle,
+    issue_store: &IssueStore,
+    day: NaiveDate,
+    workday_hours: u64,
+    tz: Option<Tz>,
+) -> Result<Vec<bridge::DailySummaryEntry>, String> {
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    let day_key = day.format("%Y-%m-%d").to_string();
+    let (start_of_day, start_of_next_day) = local_day_bounds(day, tz)?;
+    let created_from = start_of_day.to_rfc3339();
+    let created_to = start_of_next_day.to_rfc3339();
+
+    let created_by = ensure_current_login(&client, &secrets).await.ok();
+
+    let entries = client
+        .get_worklogs_by_params(
+            created_by.as_deref(),
+            Some(&created_from),
+            Some(&created_to),
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut totals: HashMap<String, (u64, Option<String>)> = HashMap::new();
+
+    for entry in entries {
+        let Some(issue_ref) = entry.issue.as_ref() else {
+            continue;
+        };
+        let Some(issue_key) = normalize_owned_string(issue_ref.key.clone()) else {
+            continue;
+        };
+
+        let date_value = entry
+            .start
+            .as_deref()
+            .or(entry.created_at.as_deref())
+            .unwrap_or("");
+        let is_same_day = parse_tracker_datetime(date_value, tz)
+            .map(|date| date.format("%Y-%m-%d").to_string() == day_key)
+            .unwrap_or(false);
+        if !is_same_day {
+            continue;
+        }
+
+        let seconds = entry
+            .duration
+            .as_deref()
+            .and_then(|value| parse_tracker_duration_to_seconds(value, workday_hours))
+            .unwrap_or(0);
+        let fallback_summary = issue_ref
+            .display
+            .as_ref()
+            .and_then(coerce_display_value);
+
+        let slot = totals.entry(issue_key).or_insert((0, None));
+        slot.0 = slot.0.saturating_add(seconds);
+        if slot.1.is_none() {
+            slot.1 = fallback_summary;
+        }
+    }
+
+    let mut breakdown: Vec<bridge::DailySummaryEntry> = totals
+        .into_iter()
+        .map(|(issue_key, (seconds, fallback_summary))| {
+            let issue_summary = issue_store
+                .find(&issue_key)
+                .map(|issue| issue.summary)
+                .or(fallback_summary)
+                .unwrap_or_default();
+            bridge::DailySummaryEntry {
+                issue_key,
+                issue_summary,
+                seconds,
+            }
+        })
+        .collect();
+    breakdown.sort_by(|a, b| {
+        b.seconds
+            .cmp(&a.seconds)
+            .then_with(|| a.issue_key.cmp(&b.issue_key))
+    });
+
+    Ok(breakdown)
+}
+
+/// Returns logged time for a day (today by default) grouped per issue, largest first.
+#[tauri::command]
+async fn get_daily_worklog_breakdown(
+    app: tauri::AppHandle,
+    issue_store: tauri::State<'_, IssueStore>,
+    date: Option<String>,
+) -> Result<Vec<bridge::DailySummaryEntry>, String> {
+    let config = ConfigManager::new().load();
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+    let day = match normalize_owned_string(date) {
+        Some(value) => parse_date_bound(&value, "breakdown")?,
+        None => workday_now(tz).date_naive(),
+    };
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    fetch_daily_worklog_breakdown(&app, issue_store.inner(), day, workday_hours, tz).await
+}
+
+const DAYS_PER_WEEK: i64 = 7;
+
+/// Returns Monday of the local week containing `day`.
+fn week_start_for(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(i64::from(day.weekday().num_days_from_monday()))
+}
+
+async fn fetch_weekly_summary(
+    app: &tauri::AppHandle,
+    week_start: NaiveDate,
+    workday_hours: u64,
+    tz: Option<Tz>,
+) -> Result<Vec<bridge::DaySummary>, String> {
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    let week_end = week_start + Duration::days(DAYS_PER_WEEK - 1);
+    let (range_start, _) = local_day_bounds(week_start, tz)?;
+    let (_, range_end) = local_day_bounds(week_end, tz)?;
+    let created_from = range_start.to_rfc3339();
+    let created_to = range_end.to_rfc3339();
+
+    let created_by = ensure_current_login(&client, &secrets).await.ok();
+
+    let entries = client
+        .get_worklogs_by_params(
+            created_by.as_deref(),
+            Some(&created_from),
+            Some(&created_to),
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut days: Vec<bridge::DaySummary> = (0..DAYS_PER_WEEK)
+        .map(|offset| bridge::DaySummary {
+            date: (week_start + Duration::days(offset))
+                .format("%Y-%m-%d")
+                .to_string(),
+            total_seconds: 0,
+            entries: Vec::new(),
+        })
+        .collect();
+
+    for entry in convert_worklogs_native(entries, workday_hours) {
+        let Some(day) = parse_tracker_datetime(&entry.date, tz).map(|dt| dt.date_naive()) else {
+            continue;
+        };
+        let offset = (day - week_start).num_days();
+        if !(0..DAYS_PER_WEEK).contains(&offset) {
+            continue;
+        }
+        let slot = &mut days[offset as usize];
+        slot.total_seconds = slot.total_seconds.saturating_add(entry.duration_seconds);
+        slot.entries.push(entry);
+    }
+
+    Ok(days)
+}
+
+/// Returns per-day logged time for a 7-day week starting at `week_start_date` (defaults to this week's Monday).
+#[tauri::command]
+async fn get_weekly_summary(
+    app: tauri::AppHandle,
+    week_start_date: Option<String>,
+) -> Result<Vec<bridge::DaySummary>, String> {
+    let config = ConfigManager::new().load();
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+    let week_start = match normalize_owned_string(week_start_date) {
+        Some(value) => parse_date_bound(&value, "week start")?,
+        None => week_start_for(workday_now(tz).date_naive()),
+    };
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    fetch_weekly_summary(&app, week_start, workday_hours, tz).await
+}
+
 /// Aggregates today's logged seconds for the provided issue keys.
 #[tauri::command]
 async fn get_today_logged_seconds_for_issues(
@@ -924,7 +2037,8 @@ async fn get_today_logged_seconds_for_issues(
 
     let config = ConfigManager::new().load();
     let workday_hours = sanitize_workday_hours(config.workday_hours);
-    fetch_today_logged_seconds_for_issue_keys(&app, &issue_keys, workday_hours).await
+    let tz = parse_workday_timezone(config.workday_timezone.as_deref());
+    fetch_today_logged_seconds_for_issue_keys(&app, &issue_keys, workday_hours, tz).await
 }
 
 async fn fetch_statuses_native(
@@ -991,18 +2105,145 @@ async fn fetch_priorities_native(
     Ok(convert_simple_entities_native(priorities))
 }
 
-/// Fetches global issue type catalog.
+/// Fetches issue type catalog, optionally limited to a queue.
 async fn fetch_issue_types_native(
     secrets: SecretsManager,
+    queue_key: Option<&str>,
 ) -> Result<Vec<bridge::SimpleEntity>, String> {
     let client = build_tracker_client(&secrets)?;
     let types = client
-        .get_issue_types()
+        .list_all_issue_types(queue_key)
         .await
         .map_err(|err| err.to_string())?;
     Ok(convert_simple_entities_native(types))
 }
 
+/// Fetches details of a single queue.
+async fn fetch_queue_native(
+    secrets: SecretsManager,
+    queue_key: &str,
+) -> Result<bridge::Queue, String> {
+    if queue_key.trim().is_empty() {
+        return Err("Queue cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    let queue = client
+        .get_queue(queue_key.trim())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_queue_native(queue))
+}
+
+/// Fetches issue types configured in a queue.
+async fn fetch_queue_issue_types_native(
+    secrets: SecretsManager,
+    queue_key: &str,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    if queue_key.trim().is_empty() {
+        return Err("Queue cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    let types = client
+        .get_queue_issue_types(queue_key.trim())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_simple_entities_native(types))
+}
+
+/// Fetches components defined in a queue.
+async fn fetch_queue_components_native(
+    secrets: SecretsManager,
+    queue_key: &str,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    if queue_key.trim().is_empty() {
+        return Err("Queue cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    let components = client
+        .get_queue_components(queue_key.trim())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(convert_simple_entities_native(components))
+}
+
+/// Fetches field definitions available in a queue.
+async fn fetch_queue_fields_native(
+    secrets: SecretsManager,
+    queue_key: &str,
+) -> Result<Vec<bridge::FieldDefinition>, String> {
+    if queue_key.trim().is_empty() {
+        return Err("Queue cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    let fields = client
+        .get_queue_fields(queue_key.trim())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(fields.into_iter().map(convert_field_definition_native).collect())
+}
+
+/// Applies one field patch to several issues via a single bulk change request.
+async fn bulk_update_issues_native(
+    secrets: SecretsManager,
+    keys: &[String],
+    patch: &Value,
+) -> Result<bridge::BulkChangeResult, String> {
+    let keys: Vec<&str> = keys
+        .iter()
+        .map(|key| key.trim())
+        .filter(|key| !key.is_empty())
+        .collect();
+    if keys.is_empty() {
+        return Err("At least one issue key is required".to_string());
+    }
+    if !patch.as_object().is_some_and(|fields| !fields.is_empty()) {
+        return Err("Patch must be a non-empty object".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    let response = client
+        .bulk_update_issue_fields(&keys, patch)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(bridge::BulkChangeResult {
+        operation_id: response.operation_id,
+    })
+}
+
+/// Moves an issue to another queue; returns whether the queue actually changed.
+async fn move_issue_to_queue_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    target_queue_key: &str,
+) -> Result<bool, String> {
+    let target_queue_key = target_queue_key.trim();
+    if target_queue_key.is_empty() {
+        return Err("Target queue cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    client
+        .move_issue_to_queue(issue_key, target_queue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(!issue_queue_key(issue_key.trim()).eq_ignore_ascii_case(target_queue_key))
+}
+
+/// Creates a copy of an existing issue, optionally with a new summary.
+async fn clone_issue_native(
+    secrets: SecretsManager,
+    source_key: &str,
+    new_summary: Option<&str>,
+) -> Result<bridge::Issue, String> {
+    let new_summary = new_summary.map(str::trim).filter(|value| !value.is_empty());
+    let client = build_tracker_client(&secrets)?;
+    let issue = client
+        .clone_issue(source_key, new_summary)
+        .await
+        .map_err(|err| err.to_string())?;
+    let config = ConfigManager::new().load();
+    let workday_hours = sanitize_workday_hours(config.workday_hours);
+    Ok(convert_issue_native(issue, workday_hours))
+}
+
 /// Creates a new issue in Tracker.
 async fn create_issue_native(
     secrets: SecretsManager,
@@ -1141,6 +2382,9 @@ async fn release_scroll_context_native(
     if scroll_id.trim().is_empty() {
         return Ok(());
     }
+    if let Some(registry) = app.try_state::<ScrollContextRegistry>() {
+        registry.forget(scroll_id);
+    }
     let secrets = secrets_from_app(app)?;
     let client = build_tracker_client(&secrets)?;
     client
@@ -1149,16 +2393,96 @@ async fn release_scroll_context_native(
         .map_err(|err| err.to_string())
 }
 
+async fn release_scroll_contexts_native(
+    app: &tauri::AppHandle,
+    scroll_ids: &[String],
+) -> Result<(), String> {
+    let scroll_ids: Vec<&str> = scroll_ids
+        .iter()
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if scroll_ids.is_empty() {
+        return Ok(());
+    }
+    if let Some(registry) = app.try_state::<ScrollContextRegistry>() {
+        for scroll_id in &scroll_ids {
+            registry.forget(scroll_id);
+        }
+    }
+    let secrets = secrets_from_app(app)?;
+    let client = build_tracker_client(&secrets)?;
+    client
+        .clear_scroll_contexts(&scroll_ids)
+        .await
+        .map_err(|err| err.to_string())
+}
+
 fn convert_comments_native(comments: Vec<NativeComment>) -> Vec<bridge::Comment> {
-    comments
-        .into_iter()
-        .map(|comment| bridge::Comment {
-            id: coerce_display_value(&comment.id).unwrap_or_default(),
-            text: comment.text.unwrap_or_default(),
-            author: coerce_comment_author(&comment.created_by),
-            created_at: comment.created_at.unwrap_or_default(),
-        })
-        .collect()
+    comments.into_iter().map(convert_comment_native).collect()
+}
+
+fn convert_comment_native(comment: NativeComment) -> bridge::Comment {
+    bridge::Comment {
+        id: coerce_display_value(&comment.id).unwrap_or_default(),
+        text: comment.text.unwrap_or_default(),
+        author: coerce_comment_author(&comment.created_by),
+        author_login: comment
+            .created_by
+            .as_ref()
+            .and_then(|author| author.login.clone()),
+        created_at: comment.created_at.unwrap_or_default(),
+    }
+}
+
+/// Flattens changelog events into one bridge entry per changed field.
+///
+/// Events without field changes (for example comment additions) are kept as a
+/// single entry labelled with the event type.
+fn convert_changelog_native(entries: Vec<NativeChangelogEntry>) -> bridge::IssueHistory {
+    let mut history = Vec::new();
+    for entry in entries {
+        let id = coerce_display_value(&entry.id).unwrap_or_default();
+        let timestamp = entry.updated_at.clone().unwrap_or_default();
+        let author = coerce_comment_author(&entry.updated_by);
+        let changes = entry.fields.unwrap_or_default();
+
+        if changes.is_empty() {
+            history.push(bridge::ChangelogEntry {
+                id,
+                timestamp,
+                author,
+                field: entry.change_type.unwrap_or_else(|| "unknown".to_string()),
+                from_value: None,
+                to_value: None,
+            });
+            continue;
+        }
+
+        for change in changes {
+            let field = change
+                .field
+                .as_ref()
+                .and_then(|field| {
+                    field
+                        .display
+                        .as_ref()
+                        .and_then(coerce_display_value)
+                        .or_else(|| field.id.clone())
+                        .or_else(|| field.key.clone())
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+            history.push(bridge::ChangelogEntry {
+                id: id.clone(),
+                timestamp: timestamp.clone(),
+                author: author.clone(),
+                field,
+                from_value: change.from.as_ref().and_then(coerce_display_value),
+                to_value: change.to.as_ref().and_then(coerce_display_value),
+            });
+        }
+    }
+    bridge::IssueHistory(history)
 }
 
 fn convert_attachments_native(attachments: Vec<NativeAttachment>) -> Vec<bridge::Attachment> {
@@ -1214,15 +2538,35 @@ fn attachment_mime_type(attachment: &NativeAttachment, response_mime: Option<Str
     response_mime
         .or_else(|| attachment.mime_type.clone())
         .or_else(|| attachment.mimetype.clone())
+        .or_else(|| {
+            attachment
+                .name
+                .as_ref()
+                .and_then(coerce_display_value)
+                .and_then(|name| mime_from_extension(&name))
+                .map(str::to_string)
+        })
         .unwrap_or_else(|| "application/octet-stream".to_string())
 }
 
+/// Guesses a MIME type from a file name's extension, using the same table as uploads.
+fn mime_from_extension(name: &str) -> Option<&'static str> {
+    mime_guess::from_path(name.trim()).first_raw()
+}
+
 fn resolve_download_destination(dest_path: &str) -> Result<PathBuf, String> {
     let trimmed = dest_path.trim();
     if trimmed.is_empty() {
         return Err("Destination path cannot be empty".to_string());
     }
 
+    if let Some(rest) = trimmed.strip_prefix("~/") {
+        let home = UserDirs::new()
+            .map(|dirs| dirs.home_dir().to_path_buf())
+            .ok_or_else(|| "Unable to determine home directory".to_string())?;
+        return Ok(home.join(rest));
+    }
+
     if trimmed.contains('/') || trimmed.contains('\\') {
         return Ok(PathBuf::from(trimmed));
     }
@@ -1271,6 +2615,22 @@ async fn download_attachment_native(
     Ok(())
 }
 
+/// Rejects previews of attachments larger than `max_bytes`; unknown sizes are allowed.
+fn check_preview_size(size: Option<u64>, max_bytes: u64) -> Result<(), String> {
+    match size {
+        Some(size) if size > max_bytes => Err(format!(
+            "Attachment too large for preview ({}, limit {}); download it instead",
+            format_megabytes(size),
+            format_megabytes(max_bytes)
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn format_megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
 async fn preview_attachment_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -1278,6 +2638,8 @@ async fn preview_attachment_native(
 ) -> Result<bridge::AttachmentPreview, String> {
     let client = build_tracker_client(&secrets)?;
     let attachment = find_attachment_metadata(&client, issue_key, attachment_id).await?;
+    let max_bytes = normalize_config(ConfigManager::new().load()).preview_max_bytes;
+    check_preview_size(attachment.size, max_bytes)?;
     let url = attachment_download_url(&attachment)?;
     let binary = client
         .fetch_binary(&url)
@@ -1328,6 +2690,53 @@ async fn add_comment_native(
         .map_err(|err| err.to_string())
 }
 
+/// Performs a basic sanity check that a reaction is an emoji rather than free text.
+///
+/// Any non-empty value without whitespace or control characters that is not plain ASCII
+/// is accepted, so keycap sequences such as `1️⃣` pass; this deliberately does not try to
+/// match the full emoji table.
+fn validate_reaction_emoji(emoji: &str) -> Result<(), String> {
+    if emoji.is_empty() {
+        return Err("Reaction emoji cannot be empty".to_string());
+    }
+    if emoji.is_ascii()
+        || emoji
+            .chars()
+            .any(|ch| ch.is_whitespace() || ch.is_control())
+    {
+        return Err(format!("Reaction '{}' is not an emoji", emoji));
+    }
+    Ok(())
+}
+
+async fn add_comment_reaction_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    comment_id: &str,
+    emoji: &str,
+) -> Result<(), String> {
+    validate_reaction_emoji(emoji)?;
+    let client = build_tracker_client(&secrets)?;
+    client
+        .add_comment_reaction(issue_key, comment_id, emoji)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn remove_comment_reaction_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    comment_id: &str,
+    emoji: &str,
+) -> Result<(), String> {
+    validate_reaction_emoji(emoji)?;
+    let client = build_tracker_client(&secrets)?;
+    client
+        .remove_comment_reaction(issue_key, comment_id, emoji)
+        .await
+        .map_err(|err| err.to_string())
+}
+
 async fn update_issue_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -1335,10 +2744,134 @@ async fn update_issue_native(
     description: Option<&str>,
 ) -> Result<(), String> {
     let client = build_tracker_client(&secrets)?;
-    client
-        .update_issue_fields(issue_key, summary, description)
-        .await
-        .map_err(|err| err.to_string())
+    client
+        .update_issue_fields(issue_key, summary, description)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn fetch_issue_custom_fields_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<HashMap<String, Value>, String> {
+    let client = build_tracker_client(&secrets)?;
+    client
+        .get_issue_custom_fields(issue_key)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn update_issue_custom_field_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    field_key: &str,
+    value: Value,
+) -> Result<(), String> {
+    if field_key.trim().is_empty() {
+        return Err("Field key cannot be empty".to_string());
+    }
+    let client = build_tracker_client(&secrets)?;
+    client
+        .update_issue_custom_field(issue_key, field_key.trim(), value)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Trims tags and drops empty and repeated ones, keeping the first occurrence order.
+fn normalize_issue_tags<I>(tags: I) -> Vec<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut seen = HashSet::new();
+    tags.into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty() && seen.insert(tag.clone()))
+        .collect()
+}
+
+async fn fetch_issue_tags_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<Vec<String>, String> {
+    let client = build_tracker_client(&secrets)?;
+    client
+        .get_issue_tags(issue_key)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Replaces the issue's tag list and returns the tags that were sent.
+async fn set_issue_tags_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let tags = normalize_issue_tags(tags);
+    let tag_refs: Vec<&str> = tags.iter().map(String::as_str).collect();
+    let client = build_tracker_client(&secrets)?;
+    client
+        .set_issue_tags(issue_key, &tag_refs)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(tags)
+}
+
+/// Appends one tag to the issue's current tags; the issue is not patched when it is already there.
+async fn add_issue_tag_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    tag: &str,
+) -> Result<Vec<String>, String> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    let current = fetch_issue_tags_native(secrets.clone(), issue_key).await?;
+    if current.iter().any(|existing| existing == tag) {
+        return Ok(normalize_issue_tags(current));
+    }
+    let updated = current.into_iter().chain(std::iter::once(tag.to_string()));
+    set_issue_tags_native(secrets, issue_key, updated.collect()).await
+}
+
+/// Returns the issue's vote count; issues nobody voted for report zero.
+async fn fetch_issue_votes_native(secrets: SecretsManager, issue_key: &str) -> Result<u64, String> {
+    let client = build_tracker_client(&secrets)?;
+    let issue = client
+        .get_issue_with_fields(issue_key, "votes")
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(issue.votes.unwrap_or(0))
+}
+
+/// Adds or withdraws the current user's vote for an issue.
+async fn set_issue_vote_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    voted: bool,
+) -> Result<(), String> {
+    let client = build_tracker_client(&secrets)?;
+    let result = if voted {
+        client.vote_for_issue(issue_key).await
+    } else {
+        client.unvote_issue(issue_key).await
+    };
+    result.map_err(|err| err.to_string())
+}
+
+/// Subscribes or unsubscribes the current user from issue notifications.
+async fn set_issue_subscription_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    subscribed: bool,
+) -> Result<(), String> {
+    let client = build_tracker_client(&secrets)?;
+    let result = if subscribed {
+        client.subscribe_to_issue(issue_key).await
+    } else {
+        client.unsubscribe_from_issue(issue_key).await
+    };
+    result.map_err(|err| err.to_string())
 }
 
 async fn fetch_transitions_native(
@@ -1353,6 +2886,21 @@ async fn fetch_transitions_native(
     Ok(convert_transitions_native(transitions))
 }
 
+async fn fetch_transitions_with_fields_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+) -> Result<Vec<bridge::TransitionWithFields>, String> {
+    let client = build_tracker_client(&secrets)?;
+    let transitions = client
+        .get_transitions_with_fields(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(transitions
+        .into_iter()
+        .map(convert_transition_with_fields_native)
+        .collect())
+}
+
 async fn execute_transition_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -1367,6 +2915,118 @@ async fn execute_transition_native(
         .map_err(|err| err.to_string())
 }
 
+async fn execute_transition_with_fields_native(
+    secrets: SecretsManager,
+    issue_key: &str,
+    transition_id: &str,
+    fields: Value,
+) -> Result<(), String> {
+    let Value::Object(fields) = fields else {
+        return Err("Transition fields must be an object".to_string());
+    };
+    let fields: HashMap<String, Value> = fields.into_iter().collect();
+    let client = build_tracker_client(&secrets)?;
+    client
+        .execute_transition_with_fields(issue_key, transition_id, &fields)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Moves an issue to the first available "done" status via workflow transitions.
+async fn mark_issue_done_native(secrets: SecretsManager, issue_key: &str) -> Result<(), String> {
+    let client = build_tracker_client(&secrets)?;
+    let transitions = client
+        .get_transitions(issue_key)
+        .await
+        .map_err(|err| err.to_string())?;
+    let (transition_id, status_key) = select_done_transition(&transitions)
+        .ok_or_else(|| format!("No transition to a done status is available for {}", issue_key))?;
+    let resolution = (status_key == "closed").then_some(DEFAULT_DONE_RESOLUTION);
+    client
+        .execute_transition(issue_key, &transition_id, None, resolution)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Picks the transition leading to the most preferred "done" status, returning its id and status key.
+fn select_done_transition(transitions: &[NativeTransition]) -> Option<(String, String)> {
+    DONE_STATUS_KEYS.iter().find_map(|done_key| {
+        transitions.iter().find_map(|transition| {
+            let destination = transition.to.as_ref().or(transition.status.as_ref())?;
+            let status_key = destination.key.as_deref()?;
+            if !status_key.eq_ignore_ascii_case(done_key) {
+                return None;
+            }
+            Some((transition.id.clone()?, done_key.to_string()))
+        })
+    })
+}
+
+/// Closes an issue from the tray, emitting `issue-closed` or notifying about the failure.
+async fn close_issue_from_tray(
+    app: tauri::AppHandle,
+    issue_store: IssueStore,
+    timer: Arc<Timer>,
+    issue_key: String,
+) {
+    let result = match secrets_from_app(&app) {
+        Ok(secrets) => mark_issue_done_native(secrets, &issue_key).await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(()) => {
+            emit_issue_closed_event(&app, &issue_key);
+            if let Err(err) = refresh_issue_cache(app, issue_store, timer, None).await {
+                debug!("Issue refresh after close failed: {}", redact_log_details(&err));
+            }
+        }
+        Err(err) => {
+            warn!("Failed to mark issue as done from tray");
+            if let Err(notify_err) = app
+                .notification()
+                .builder()
+                .title(format!("Could not close {}", issue_key))
+                .body(truncate_text(&collapse_whitespace(&err), 120))
+                .show()
+            {
+                warn!("Failed to show close failure notification: {}", notify_err);
+            }
+        }
+    }
+}
+
+/// Maps Tracker errors to bridge strings; an authentication failure emits `session-expired`
+/// and becomes the [`SESSION_EXPIRED_ERROR`] marker so the frontend can return to login.
+fn handle_auth_error<T>(
+    app: &tauri::AppHandle,
+    result: Result<T, TrackerError>,
+) -> Result<T, String> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(TrackerError::Authentication(message)) => {
+            debug!(
+                "Tracker rejected stored token: {}",
+                redact_log_details(&message)
+            );
+            if let Err(err) = app.emit("session-expired", ()) {
+                warn!("Failed to emit session-expired event: {}", err);
+            }
+            Err(SESSION_EXPIRED_ERROR.to_string())
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Emits `issue-closed` so the frontend can drop or refresh the issue.
+fn emit_issue_closed_event(app: &tauri::AppHandle, issue_key: &str) {
+    let payload = IssueClosedPayload {
+        issue_key: issue_key.to_string(),
+    };
+    if let Err(err) = app.emit("issue-closed", &payload) {
+        warn!("Failed to emit issue-closed event: {}", err);
+    }
+}
+
 async fn log_work_native(
     secrets: SecretsManager,
     issue_key: &str,
@@ -1402,6 +3062,7 @@ fn parse_duration_to_iso(input: &str) -> Result<String, String> {
     let mut days = 0u64;
     let mut hours = 0u64;
     let mut minutes = 0u64;
+    let mut seconds = 0u64;
 
     for capture in DURATION_TOKEN_REGEX.captures_iter(&normalized) {
         let value = capture[1]
@@ -1412,11 +3073,12 @@ fn parse_duration_to_iso(input: &str) -> Result<String, String> {
             "d" => days += value,
             "h" => hours += value,
             "m" => minutes += value,
+            "s" => seconds += value,
             _ => {}
         }
     }
 
-    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 {
+    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 && seconds == 0 {
         if let Ok(value) = normalized.parse::<u64>() {
             minutes = value;
         } else if let Ok(value) = normalized.parse::<f64>() {
@@ -1430,7 +3092,7 @@ fn parse_duration_to_iso(input: &str) -> Result<String, String> {
         }
     }
 
-    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 {
+    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 && seconds == 0 {
         return Err("Duration resolves to zero".to_string());
     }
 
@@ -1441,7 +3103,7 @@ fn parse_duration_to_iso(input: &str) -> Result<String, String> {
     if days > 0 {
         iso.push_str(&format!("{}D", days));
     }
-    if hours > 0 || minutes > 0 {
+    if hours > 0 || minutes > 0 || seconds > 0 {
         iso.push('T');
         if hours > 0 {
             iso.push_str(&format!("{}H", hours));
@@ -1449,6 +3111,9 @@ fn parse_duration_to_iso(input: &str) -> Result<String, String> {
         if minutes > 0 {
             iso.push_str(&format!("{}M", minutes));
         }
+        if seconds > 0 {
+            iso.push_str(&format!("{}S", seconds));
+        }
     }
 
     if iso == "P" {
@@ -1496,6 +3161,18 @@ fn convert_simple_entity_native(entity: NativeSimpleEntity) -> bridge::SimpleEnt
     bridge::SimpleEntity { key, display }
 }
 
+fn convert_queue_native(queue: NativeQueue) -> bridge::Queue {
+    let name = normalize_owned_string(queue.name).unwrap_or_else(|| queue.key.clone());
+    bridge::Queue {
+        id: queue.id,
+        key: queue.key,
+        name,
+        description: normalize_owned_string(queue.description),
+        lead: queue.lead.map(convert_user_profile),
+        default_type: queue.default_type.map(convert_simple_entity_native),
+    }
+}
+
 fn convert_project_entity_native(mut entity: NativeSimpleEntity) -> bridge::SimpleEntity {
     let key = entity
         .id
@@ -1521,6 +3198,22 @@ fn convert_project_entity_native(mut entity: NativeSimpleEntity) -> bridge::Simp
     bridge::SimpleEntity { key, display }
 }
 
+fn convert_field_definition_native(field: NativeFieldDefinition) -> bridge::FieldDefinition {
+    let name = field
+        .name
+        .as_ref()
+        .and_then(coerce_display_value)
+        .unwrap_or_else(|| field.id.clone());
+
+    bridge::FieldDefinition {
+        id: field.id,
+        name,
+        type_id: field.type_id,
+        required: field.required,
+        allowed_values: convert_simple_entities_native(field.allowed_values),
+    }
+}
+
 fn coerce_display_value(value: &Value) -> Option<String> {
     match value {
         Value::String(text) => {
@@ -1548,6 +3241,21 @@ fn coerce_display_value(value: &Value) -> Option<String> {
     }
 }
 
+/// Like [`coerce_display_value`], but joins every displayable array item with `", "`.
+fn coerce_display_value_array(value: &Value) -> Option<String> {
+    match value {
+        Value::Array(items) => {
+            let values: Vec<String> = items.iter().filter_map(coerce_display_value).collect();
+            if values.is_empty() {
+                None
+            } else {
+                Some(values.join(", "))
+            }
+        }
+        other => coerce_display_value(other),
+    }
+}
+
 fn coerce_comment_author(author: &Option<NativeCommentAuthor>) -> String {
     author
         .as_ref()
@@ -1557,25 +3265,63 @@ fn coerce_comment_author(author: &Option<NativeCommentAuthor>) -> String {
                 .and_then(coerce_display_value)
                 .or_else(|| user.login.clone())
                 .or_else(|| user.email.clone())
+                .or_else(|| comment_author_uid_label(user))
         })
         .unwrap_or_else(|| "Unknown".to_string())
 }
 
+/// Labels an otherwise anonymous author by numeric uid so distinct users stay distinguishable.
+fn comment_author_uid_label(author: &NativeCommentAuthor) -> Option<String> {
+    let uid = match author.id.as_ref()? {
+        Value::Number(number) => number.as_u64()?,
+        Value::String(text) => text.trim().parse::<u64>().ok()?,
+        _ => return None,
+    };
+    Some(format!("User #{}", uid))
+}
+
 fn convert_transitions_native(transitions: Vec<NativeTransition>) -> Vec<bridge::Transition> {
-    transitions
-        .into_iter()
-        .map(|transition| bridge::Transition {
-            id: transition.id.unwrap_or_else(|| "unknown".to_string()),
-            name: transition
-                .display
-                .as_ref()
-                .and_then(coerce_display_value)
-                .or_else(|| transition.name.as_ref().and_then(coerce_display_value))
-                .unwrap_or_else(|| "Transition".to_string()),
-            to_status: convert_transition_status(transition.status.as_ref())
-                .or_else(|| convert_transition_status(transition.to.as_ref())),
-        })
-        .collect()
+    transitions.into_iter().map(convert_transition_native).collect()
+}
+
+fn convert_transition_native(transition: NativeTransition) -> bridge::Transition {
+    bridge::Transition {
+        id: transition.id.unwrap_or_else(|| "unknown".to_string()),
+        name: transition
+            .display
+            .as_ref()
+            .and_then(coerce_display_value)
+            .or_else(|| transition.name.as_ref().and_then(coerce_display_value))
+            .unwrap_or_else(|| "Transition".to_string()),
+        description: transition
+            .description
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty()),
+        to_status: convert_transition_status(transition.status.as_ref())
+            .or_else(|| convert_transition_status(transition.to.as_ref())),
+    }
+}
+
+fn convert_transition_with_fields_native(
+    transition: NativeTransitionWithFields,
+) -> bridge::TransitionWithFields {
+    let bridge::Transition {
+        id,
+        name,
+        description,
+        to_status,
+    } = convert_transition_native(transition.transition);
+    bridge::TransitionWithFields {
+        id,
+        name,
+        description,
+        to_status,
+        required_fields: transition
+            .required_fields
+            .into_iter()
+            .map(convert_field_definition_native)
+            .collect(),
+    }
 }
 
 fn sanitize_workday_hours(hours: u8) -> u64 {
@@ -1596,19 +3342,74 @@ fn sanitize_workday_time(value: String, fallback: &str) -> String {
 }
 
 fn normalize_config(mut config: Config) -> Config {
+    config.schema_version = CONFIG_SCHEMA_VERSION;
     config.workday_hours = sanitize_workday_hours(config.workday_hours) as u8;
     config.workday_start_time = sanitize_workday_time(config.workday_start_time, "09:00");
     config.workday_end_time = sanitize_workday_time(config.workday_end_time, "17:00");
     if config.timer_notification_interval == 0 {
         config.timer_notification_interval = 1;
     }
+    config.custom_motivational_phrases = config
+        .custom_motivational_phrases
+        .iter()
+        .map(|phrase| collapse_whitespace(phrase))
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+    config.proxy_url = config
+        .proxy_url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty());
+    config.no_proxy = config
+        .no_proxy
+        .map(|hosts| hosts.trim().to_string())
+        .filter(|hosts| !hosts.is_empty());
+    config.workday_timezone = config
+        .workday_timezone
+        .filter(|name| parse_workday_timezone(Some(name)).is_some())
+        .map(|name| name.trim().to_string());
+    config.issue_display_fields = normalize_issue_display_fields(config.issue_display_fields);
+    if config.preview_max_bytes == 0 {
+        config.preview_max_bytes = DEFAULT_PREVIEW_MAX_BYTES;
+    }
     config
 }
 
+/// Fields every issue list projection must include so rows stay identifiable.
+const REQUIRED_ISSUE_DISPLAY_FIELDS: [&str; 2] = ["key", "summary"];
+
+/// Trims and de-duplicates configured issue list fields, keeping `key` and `summary` first.
+///
+/// An empty list stays empty so the API default projection is used.
+fn normalize_issue_display_fields(fields: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for field in fields.iter().map(|field| field.trim()) {
+        if !field.is_empty() && !normalized.iter().any(|existing| existing == field) {
+            normalized.push(field.to_string());
+        }
+    }
+    if normalized.is_empty() {
+        return normalized;
+    }
+    for required in REQUIRED_ISSUE_DISPLAY_FIELDS.iter().rev() {
+        normalized.retain(|field| field != required);
+        normalized.insert(0, required.to_string());
+    }
+    normalized
+}
+
 fn parse_duration_value_to_seconds(value: &Value, workday_hours: u64) -> Option<u64> {
     match value {
         Value::String(text) => parse_tracker_duration_to_seconds(text, workday_hours),
-        Value::Number(number) => number.as_u64(),
+        // Integers are already seconds; floats are fractional hours (e.g. `1.5` is 1h 30m),
+        // matching how `parse_duration_to_iso` reads bare decimals.
+        Value::Number(number) => number.as_u64().or_else(|| {
+            let hours = number
+                .as_f64()
+                .filter(|value| value.is_finite() && *value >= 0.0)?;
+            let whole_hours = hours.trunc();
+            let minutes = ((hours - whole_hours) * 60.0).round();
+            Some(whole_hours as u64 * 3600 + minutes as u64 * 60)
+        }),
         Value::Object(map) => {
             for key in ["duration", "value", "display", "text", "en", "ru"] {
                 if let Some(candidate) = map.get(key) {
@@ -1636,19 +3437,41 @@ fn parse_tracker_duration_to_seconds(input: &str, workday_hours: u64) -> Option<
     let mut days = 0u64;
     let mut hours = 0u64;
     let mut minutes = 0u64;
+    let mut seconds = 0u64;
 
-    for capture in DURATION_TOKEN_REGEX.captures_iter(&normalized) {
-        let value = capture[1].parse::<u64>().ok()?;
-        match &capture[2] {
-            "w" => weeks += value,
-            "d" => days += value,
-            "h" => hours += value,
-            "m" => minutes += value,
-            _ => {}
+    // Tracker usually answers with shorthand (`1h 30m`), but some endpoints use ISO 8601 (`PT1H30M`).
+    let iso_capture = if normalized.starts_with('p') {
+        ISO_DURATION_REGEX.captures(&normalized)
+    } else {
+        None
+    };
+    if let Some(capture) = iso_capture {
+        let component = |index: usize| -> Option<u64> {
+            capture
+                .get(index)
+                .map(|value| value.as_str().parse::<u64>().ok())
+                .unwrap_or(Some(0))
+        };
+        weeks = component(1)?;
+        days = component(2)?;
+        hours = component(3)?;
+        minutes = component(4)?;
+        seconds = component(5)?;
+    } else {
+        for capture in DURATION_TOKEN_REGEX.captures_iter(&normalized) {
+            let value = capture[1].parse::<u64>().ok()?;
+            match &capture[2] {
+                "w" => weeks += value,
+                "d" => days += value,
+                "h" => hours += value,
+                "m" => minutes += value,
+                "s" => seconds += value,
+                _ => {}
+            }
         }
     }
 
-    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 {
+    if weeks == 0 && days == 0 && hours == 0 && minutes == 0 && seconds == 0 {
         return None;
     }
 
@@ -1657,7 +3480,8 @@ fn parse_tracker_duration_to_seconds(input: &str, workday_hours: u64) -> Option<
         weeks * WORKDAYS_PER_WEEK * workday_hours * 3600
             + days * workday_hours * 3600
             + hours * 3600
-            + minutes * 60,
+            + minutes * 60
+            + seconds,
     )
 }
 
@@ -1677,6 +3501,7 @@ fn convert_worklogs_native(entries: Vec<NativeWorklogEntry>, workday_hours: u64)
                 .unwrap_or(0),
             comment: entry.comment.unwrap_or_default(),
             author: coerce_comment_author(&entry.created_by),
+            issue_key: entry.issue.as_ref().and_then(|issue| issue.key.clone()),
         })
         .collect()
 }
@@ -1717,12 +3542,79 @@ fn get_config() -> Config {
     normalize_config(cm.load())
 }
 
-/// Saves desktop configuration after normalization/canonicalization.
+/// Saves desktop configuration after normalization/canonicalization and emits `config-changed`.
 #[tauri::command]
-fn save_config(config: Config) -> Result<(), String> {
+fn save_config(app: tauri::AppHandle, config: Config) -> Result<(), String> {
     let cm = ConfigManager::new();
     let normalized = normalize_config(config);
-    cm.save(&normalized).map_err(|e| e.to_string())
+    cm.save(&normalized).map_err(|e| e.to_string())?;
+    if let Err(err) = app.emit("config-changed", &normalized) {
+        warn!("Failed to emit config-changed event: {}", err);
+    }
+    Ok(())
+}
+
+/// Overwrites the config file with defaults without reading it, so corrupt files are recoverable.
+#[tauri::command]
+fn reset_config(app: tauri::AppHandle) -> Result<(), String> {
+    let defaults = normalize_config(Config::default());
+    ConfigManager::new()
+        .save(&defaults)
+        .map_err(|err| err.to_string())?;
+    if let Err(err) = app.emit("config-reset", &defaults) {
+        warn!("Failed to emit config-reset event: {}", err);
+    }
+    Ok(())
+}
+
+/// Returns the current normalized config as pretty-printed JSON for transfer to another machine.
+#[tauri::command]
+fn export_config() -> Result<String, String> {
+    let config = normalize_config(ConfigManager::new().load());
+    serde_json::to_string_pretty(&config).map_err(|err| err.to_string())
+}
+
+/// Validates, normalizes and saves a config JSON document produced by `export_config`,
+/// then emits `config-changed` with the imported config.
+fn import_config_native(app: &tauri::AppHandle, json: &str) -> Result<(), String> {
+    let config = normalize_config(Config::from_json(json)?);
+    ConfigManager::new()
+        .save(&config)
+        .map_err(|err| err.to_string())?;
+    if let Err(err) = app.emit("config-changed", &config) {
+        warn!("Failed to emit config-changed event: {}", err);
+    }
+    Ok(())
+}
+
+/// Imports config from a JSON string.
+#[tauri::command]
+fn import_config(app: tauri::AppHandle, json: String) -> Result<(), String> {
+    import_config_native(&app, &json)
+}
+
+/// Lets the user pick a JSON file and imports config from it; returns `false` when cancelled.
+#[tauri::command]
+async fn import_config_from_file(app: tauri::AppHandle) -> Result<bool, String> {
+    let dialog = app
+        .dialog()
+        .file()
+        .set_title("Import settings")
+        .add_filter("JSON", &["json"]);
+    let picked = task::spawn_blocking(move || dialog.blocking_pick_file())
+        .await
+        .map_err(|err| format!("Failed to open file picker: {}", err))?;
+    let Some(file_path) = picked else {
+        return Ok(false);
+    };
+    let path = file_path
+        .into_path()
+        .map_err(|err| format!("Unsupported file location: {}", err))?;
+    let json = async_fs::read_to_string(&path)
+        .await
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+    import_config_native(&app, &json)?;
+    Ok(true)
 }
 
 /// Returns non-secret metadata about configured OAuth client credentials.
@@ -1737,15 +3629,67 @@ async fn get_client_credentials_info(
     Ok(info)
 }
 
-/// Reports whether an OAuth session token is currently available.
+/// Stores replacement OAuth client credentials that take precedence over build-time values.
+#[tauri::command]
+async fn save_client_credentials(
+    secrets: tauri::State<'_, SecretsManager>,
+    client_id: String,
+    client_secret: String,
+) -> Result<(), String> {
+    let manager = secrets.inner().clone();
+    task::spawn_blocking(move || manager.rotate_credentials(&client_id, &client_secret))
+        .await
+        .map_err(|err| format!("Failed to save client credentials: {}", err))?
+}
+
+/// Returns a snapshot of API rate limiter hit and throttle counters.
+#[tauri::command]
+fn get_rate_limiter_metrics(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> bridge::RateLimiterMetrics {
+    let snapshot = secrets.get_rate_limiter_metrics().snapshot();
+    bridge::RateLimiterMetrics {
+        total_hits: snapshot.total_hits,
+        throttled_hits: snapshot.throttled_hits,
+        last_throttle_ms: snapshot
+            .last_throttle_duration
+            .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)),
+    }
+}
+
+/// Reports whether a stored OAuth session token is present and still accepted by Yandex.
+///
+/// When validity cannot be determined (e.g. offline), the stored session is trusted.
 #[tauri::command]
 async fn has_session(secrets: tauri::State<'_, SecretsManager>) -> Result<bool, String> {
     let manager = secrets.inner().clone();
-    let has_session = task::spawn_blocking(move || manager.get_session())
+    let session = task::spawn_blocking(move || manager.get_session())
         .await
-        .map_err(|err| format!("Failed to check session: {}", err))??
-        .is_some();
-    Ok(has_session)
+        .map_err(|err| format!("Failed to check session: {}", err))??;
+    let Some(session) = session else {
+        return Ok(false);
+    };
+
+    let client_id = secrets.get_public_info()?.client_id.unwrap_or_default();
+    let proxy = secrets.get_proxy_settings();
+    match auth::validate_token(
+        &session.token,
+        &client_id,
+        proxy.proxy_url.as_deref(),
+        proxy.no_proxy.as_deref(),
+    )
+    .await
+    {
+        Ok(valid) => Ok(valid),
+        Err(err) => {
+            warn!("Failed to validate session token; trusting stored session");
+            debug!(
+                "Token validation details: {}",
+                redact_log_details(&err.to_string())
+            );
+            Ok(true)
+        }
+    }
 }
 
 /// Exchanges OAuth authorization code for tokens and persists session.
@@ -1754,21 +3698,39 @@ async fn exchange_code(
     code: String,
     org_id: Option<String>,
     org_type: String,
+    code_verifier: Option<String>,
     secrets: tauri::State<'_, SecretsManager>,
 ) -> Result<bool, String> {
-    let credentials = secrets
-        .get_credentials()
-        .map_err(|e| format!("Failed to read client credentials: {}", e))?
-        .ok_or_else(|| {
-            "Client credentials are missing. Configure your OAuth app credentials before logging in."
-                .to_string()
-        })?;
+    let code_verifier = normalize_owned_string(code_verifier);
+    let mode = if code_verifier.is_some() {
+        AuthMode::Pkce
+    } else {
+        AuthMode::ClientSecret
+    };
 
     let normalized_org_type = canonical_org_type(&org_type);
-    let token_response =
-        auth::exchange_code(&code, &credentials.client_id, &credentials.client_secret)
-            .await
-            .map_err(|err| err.to_string())?;
+    let token_response = match (mode, code_verifier) {
+        (AuthMode::Pkce, Some(verifier)) => {
+            let client_id = secrets
+                .get_public_info()?
+                .client_id
+                .ok_or_else(|| {
+                    "Client ID is missing. Configure your OAuth app before logging in.".to_string()
+                })?;
+            auth::exchange_code_pkce(&code, &verifier, &client_id).await
+        }
+        _ => {
+            let credentials = secrets
+                .get_credentials()
+                .map_err(|e| format!("Failed to read client credentials: {}", e))?
+                .ok_or_else(|| {
+                    "Client credentials are missing. Configure your OAuth app credentials before logging in."
+                        .to_string()
+                })?;
+            auth::exchange_code(&code, &credentials.client_id, &credentials.client_secret).await
+        }
+    }
+    .map_err(|err| err.to_string())?;
 
     secrets.save_session(
         &token_response.access_token,
@@ -1779,6 +3741,49 @@ async fn exchange_code(
     Ok(true)
 }
 
+/// Generates a PKCE verifier/challenge pair for starting an OAuth flow without a client secret.
+#[tauri::command]
+fn generate_pkce_pair() -> bridge::PkcePair {
+    let (code_verifier, code_challenge) = auth::generate_pkce_pair();
+    bridge::PkcePair {
+        code_verifier,
+        code_challenge,
+    }
+}
+
+/// Counts issues matching a query or filter with a single API call, for badges.
+#[tauri::command]
+async fn get_issue_count(
+    app: tauri::AppHandle,
+    query: Option<String>,
+    filter: Option<Value>,
+) -> Result<u64, String> {
+    let query = normalize_owned_string(query);
+    let filter_map = normalize_filter_map(filter);
+    if query.is_none() && filter_map.is_none() {
+        return Err("Query or filter is required".to_string());
+    }
+    let params = IssueSearchParams::new(query, filter_map);
+    fetch_issue_count_native(&app, &params).await
+}
+
+/// Searches issues whose summary or description contains the given text.
+#[tauri::command]
+async fn fulltext_search_issues(
+    app: tauri::AppHandle,
+    text: String,
+) -> Result<Vec<bridge::Issue>, String> {
+    let text = collapse_whitespace(&text);
+    if text.chars().count() < FULLTEXT_MIN_CHARS {
+        return Err(format!(
+            "Search text must be at least {} characters",
+            FULLTEXT_MIN_CHARS
+        ));
+    }
+    let params = IssueSearchParams::default().with_fulltext(&text);
+    fetch_issues_native(&app, &params).await
+}
+
 /// Searches issues with optional query/filter and scroll pagination support.
 #[tauri::command]
 async fn get_issues(
@@ -1789,6 +3794,62 @@ async fn get_issues(
     filter: Option<Value>,
     scroll_id: Option<String>,
 ) -> Result<IssuePagePayload, String> {
+    let display_fields = cached_config(&app).issue_display_fields;
+    let search_params = issue_list_search_params(query, filter, &display_fields);
+
+    log_issue_fetch_start(
+        scroll_id.as_deref(),
+        search_params.query.as_deref(),
+        search_params.filter.as_ref(),
+    );
+
+    let page = fetch_issue_page_native(&app, &search_params, scroll_id.as_deref()).await?;
+
+    log_issue_fetch_result(
+        scroll_id.as_deref(),
+        page.issues.len(),
+        page.has_more,
+        page.next_scroll_id.as_deref(),
+    );
+
+    if scroll_id.is_none() {
+        issue_store.set(page.issues.clone());
+        let state = timer.get_state();
+        if let Err(err) = update_tray_menu(&app, &page.issues, &state) {
+            warn!("Failed to update tray state: {}", err);
+        }
+    }
+
+    Ok(page)
+}
+
+/// Preloads the next issue page for a scroll id so the following `get_issues` call returns instantly.
+///
+/// `query` and `filter` must match the search that produced `scroll_id`.
+#[tauri::command]
+async fn prefetch_next_issue_page(
+    app: tauri::AppHandle,
+    query: Option<String>,
+    filter: Option<Value>,
+    scroll_id: String,
+) -> Result<(), String> {
+    let scroll_id = scroll_id.trim();
+    if scroll_id.is_empty() {
+        return Err("Scroll id is required".to_string());
+    }
+    let display_fields = cached_config(&app).issue_display_fields;
+    let search_params = issue_list_search_params(query, filter, &display_fields);
+    prefetch_issue_page_native(&app, &search_params, scroll_id).await
+}
+
+/// Builds issue list search params, falling back to the default query when neither query nor filter is set.
+///
+/// `display_fields` comes from the normalized config; when non-empty it limits the returned fields.
+fn issue_list_search_params(
+    query: Option<String>,
+    filter: Option<Value>,
+    display_fields: &[String],
+) -> IssueSearchParams {
     let normalized_query = query.and_then(|value| {
         let trimmed = value.trim();
         if trimmed.is_empty() {
@@ -1809,45 +3870,51 @@ async fn get_issues(
         Some(DEFAULT_ISSUE_QUERY.to_string())
     };
 
-    log_issue_fetch_start(
-        scroll_id.as_deref(),
-        active_query.as_deref(),
-        filter_map.as_ref(),
-    );
-
     let search_params = IssueSearchParams::new(active_query, filter_map);
-
-    let page = fetch_issue_page_native(&app, &search_params, scroll_id.as_deref()).await?;
-
-    log_issue_fetch_result(
-        scroll_id.as_deref(),
-        page.has_more,
-        page.next_scroll_id.as_deref(),
-    );
-
-    if scroll_id.is_none() {
-        issue_store.set(page.issues.clone());
-        let state = timer.get_state();
-        if let Err(err) = update_tray_menu(&app, &page.issues, &state) {
-            warn!("Failed to update tray state: {}", err);
-        }
+    if display_fields.is_empty() {
+        search_params
+    } else {
+        search_params.with_fields(&display_fields.join(","))
     }
-
-    Ok(page)
 }
 
 /// Normalizes raw filter payload into non-empty JSON object map.
 fn normalize_filter_map(filter: Option<Value>) -> Option<JsonMap<String, Value>> {
     filter.and_then(|value| match value {
-        Value::Object(map) if !map.is_empty() => Some(map),
+        Value::Object(map) if !map.is_empty() => {
+            validate_filter_map(&map);
+            Some(map)
+        }
         _ => None,
     })
 }
 
-/// Shortens scroll ids for debug-safe logging.
+/// Warns about filter keys outside [`KNOWN_FILTER_KEYS`] (typos silently match nothing).
+///
+/// The filter is never rejected since queues may define custom fields; returns the unknown keys.
+fn validate_filter_map(map: &JsonMap<String, Value>) -> Vec<&str> {
+    let unknown: Vec<&str> = map
+        .keys()
+        .map(String::as_str)
+        .filter(|key| !KNOWN_FILTER_KEYS.contains(key))
+        .collect();
+    if !unknown.is_empty() {
+        warn!(
+            "Issue filter contains unknown keys (typo or custom field?): {}",
+            unknown.join(", ")
+        );
+    }
+    unknown
+}
+
+/// Shortens scroll ids for debug-safe logging, unless `YTRACKER_FULL_SCROLL_IDS=1` is set.
 fn describe_scroll_id(scroll_id: Option<&str>) -> String {
+    format_scroll_id(scroll_id, *FULL_SCROLL_IDS)
+}
+
+fn format_scroll_id(scroll_id: Option<&str>, full: bool) -> String {
     match scroll_id {
-        Some(id) if id.len() > 12 => format!("{}…", &id[..12]),
+        Some(id) if !full && id.len() > 12 => format!("{}…", &id[..12]),
         Some(id) => id.to_string(),
         None => "root".to_string(),
     }
@@ -1875,12 +3942,14 @@ fn log_issue_fetch_start(
 /// Emits structured debug log after issue page fetch.
 fn log_issue_fetch_result(
     scroll_id: Option<&str>,
+    count: usize,
     has_more: bool,
     next_scroll_id: Option<&str>,
 ) {
     debug!(
-        "tracker:get_issues result scroll={} has_more={} next_scroll={}",
+        "tracker:get_issues result scroll={} count={} has_more={} next_scroll={}",
         describe_scroll_id(scroll_id),
+        count,
         has_more,
         describe_scroll_id(next_scroll_id)
     );
@@ -1890,15 +3959,17 @@ fn log_issue_fetch_result(
 async fn resolve_filter_shortcuts(
     params: &mut IssueSearchParams,
     client: &TrackerClient,
+    secrets: &SecretsManager,
 ) -> Result<(), String> {
     let filter = match params.filter.as_mut() {
         Some(filter) => filter,
         None => return Ok(()),
     };
 
-    if let Some(value) = filter.get_mut("assignee") {
-        let mut cached_login: Option<String> = None;
-        rewrite_me_tokens(value, client, &mut cached_login).await?;
+    for field in USER_FILTER_FIELDS {
+        if let Some(value) = filter.get_mut(field) {
+            rewrite_me_tokens(value, client, secrets).await?;
+        }
     }
 
     Ok(())
@@ -1908,12 +3979,12 @@ async fn resolve_filter_shortcuts(
 async fn rewrite_me_tokens(
     value: &mut Value,
     client: &TrackerClient,
-    cached_login: &mut Option<String>,
+    secrets: &SecretsManager,
 ) -> Result<(), String> {
     match value {
         Value::String(text) => {
             if is_me_token(text) {
-                let login = ensure_current_login(client, cached_login).await?;
+                let login = ensure_current_login(client, secrets).await?;
                 *text = login;
             }
         }
@@ -1922,7 +3993,7 @@ async fn rewrite_me_tokens(
             for item in items.iter_mut() {
                 if let Value::String(text) = item {
                     if is_me_token(text) {
-                        let login = ensure_current_login(client, cached_login).await?;
+                        let login = ensure_current_login(client, secrets).await?;
                         *text = login.clone();
                         changed = true;
                     }
@@ -1937,9 +4008,10 @@ async fn rewrite_me_tokens(
     Ok(())
 }
 
-/// Returns whether value equals Tracker self shortcut token.
+/// Returns whether value is the Tracker self shortcut: `me()` or bare `me`, trimmed and case-insensitive.
 fn is_me_token(value: &str) -> bool {
-    value.trim().eq_ignore_ascii_case("me()")
+    let trimmed = value.trim();
+    trimmed.eq_ignore_ascii_case("me()") || trimmed.eq_ignore_ascii_case("me")
 }
 
 /// Trims owned strings and maps empty values to `None`.
@@ -1954,26 +4026,15 @@ fn normalize_owned_string(value: Option<String>) -> Option<String> {
     })
 }
 
-/// Resolves current user login once and caches it for token rewriting.
+/// Resolves current user login from the session-cached profile for token rewriting.
 async fn ensure_current_login(
     client: &TrackerClient,
-    cached_login: &mut Option<String>,
+    secrets: &SecretsManager,
 ) -> Result<String, String> {
-    if let Some(login) = cached_login.clone() {
-        return Ok(login);
-    }
-
-    let profile = client
-        .get_myself()
-        .await
-        .map_err(|err| err.to_string())?;
-
-    let login = normalize_owned_string(profile.login)
+    let profile = get_myself_cached(client, secrets).await?;
+    normalize_owned_string(profile.login)
         .or_else(|| normalize_owned_string(profile.email))
-        .ok_or_else(|| "Unable to determine current user login".to_string())?;
-
-    *cached_login = Some(login.clone());
-    Ok(login)
+        .ok_or_else(|| "Unable to determine current user login".to_string())
 }
 
 /// Removes duplicated string values from JSON arrays in-place.
@@ -2000,6 +4061,52 @@ async fn get_issue(
     fetch_issue_detail_native(secrets_clone, &issue_key).await
 }
 
+/// Returns the parent of a sub-task, or `None` when the issue has no parent.
+#[tauri::command]
+async fn get_parent_issue(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Option<bridge::Issue>, String> {
+    let secrets_clone = secrets.inner().clone();
+    let issue = fetch_issue_detail_native(secrets_clone.clone(), &issue_key).await?;
+    match issue.parent_key {
+        Some(parent_key) => fetch_issue_detail_native(secrets_clone, &parent_key)
+            .await
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Fetches issues linked to the given issue together with relation metadata.
+#[tauri::command]
+async fn get_issue_linked_issues(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::LinkedIssue>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_linked_issues_native(secrets_clone, &issue_key).await
+}
+
+/// Opens the issue page in the system browser using the current session's org type.
+#[tauri::command]
+async fn open_issue_in_browser(
+    app: tauri::AppHandle,
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    if issue_key.trim().is_empty() {
+        return Err("Issue key cannot be empty".to_string());
+    }
+    let session = secrets
+        .get_session()
+        .map_err(|e| format!("Failed to load stored token: {}", e))?
+        .ok_or_else(|| "Not authenticated. Sign in again to continue.".to_string())?;
+    let url = get_issue_url(&issue_key, &parse_org_type(&session.org_type));
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|err| format!("Failed to open browser: {}", err))
+}
+
 /// Fetches comments for a given issue.
 #[tauri::command]
 async fn get_comments(
@@ -2010,6 +4117,57 @@ async fn get_comments(
     fetch_comments_native(secrets_clone, &issue_key).await
 }
 
+/// Returns app, Tracker API and platform version details; works without a session.
+#[tauri::command]
+fn get_version_info() -> bridge::VersionInfo {
+    bridge::VersionInfo {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        api_version: ytracker_api::config::DEFAULT_API_VERSION.to_string(),
+        build_date: option_env!("YTRACKER_BUILD_DATE").map(|value| value.to_string()),
+        platform: format!("{}-{}", env::consts::OS, env::consts::ARCH),
+    }
+}
+
+/// Checks whether Tracker API is reachable, for the frontend connection status indicator.
+///
+/// Resolves to `false` when offline; errors returned by a reachable API are passed through.
+#[tauri::command]
+async fn check_connectivity(
+    app: tauri::AppHandle,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<bool, String> {
+    let client = build_tracker_client(secrets.inner())?;
+    match client.check_connectivity().await {
+        Ok(()) => Ok(true),
+        Err(TrackerError::Network(message)) => {
+            debug!("Connectivity check failed: {}", message);
+            Ok(false)
+        }
+        Err(err) => handle_auth_error(&app, Err(err)),
+    }
+}
+
+/// Fetches a single comment of a given issue by its id.
+#[tauri::command]
+async fn get_comment(
+    issue_key: String,
+    comment_id: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<bridge::Comment, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_comment_native(secrets_clone, &issue_key, &comment_id).await
+}
+
+/// Fetches change history for a given issue as a flat list of field changes.
+#[tauri::command]
+async fn get_issue_changelog(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<bridge::IssueHistory, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_changelog_native(secrets_clone, &issue_key).await
+}
+
 /// Fetches worklog history for a given issue.
 #[tauri::command]
 async fn get_issue_worklogs(
@@ -2020,6 +4178,49 @@ async fn get_issue_worklogs(
     fetch_worklogs_native(secrets_clone, &issue_key).await
 }
 
+/// Fetches worklogs of an issue within an inclusive `YYYY-MM-DD` date range.
+#[tauri::command]
+async fn get_issue_worklogs_in_range(
+    issue_key: String,
+    from_date: String,
+    to_date: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::WorklogEntry>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_worklogs_in_range_native(secrets_clone, &issue_key, &from_date, &to_date).await
+}
+
+/// Exports worklogs of the given issues within an inclusive date range to a CSV file.
+///
+/// Returns the number of data rows written (the header row is not counted).
+#[tauri::command]
+async fn export_worklogs_csv(
+    issue_keys: Vec<String>,
+    date_from: String,
+    date_to: String,
+    dest_path: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<usize, String> {
+    let secrets_clone = secrets.inner().clone();
+    export_worklogs_csv_native(secrets_clone, &issue_keys, &date_from, &date_to, &dest_path).await
+}
+
+/// Exports every issue matching the query or filter to a JSON file; returns the issue count.
+///
+/// Unlike `get_issues`, the export always requests the full summary field set.
+#[tauri::command]
+async fn export_all_issues_json(
+    app: tauri::AppHandle,
+    query: Option<String>,
+    filter: Option<Value>,
+    dest_path: String,
+) -> Result<usize, String> {
+    let display_fields = cached_config(&app).issue_display_fields;
+    let mut params = issue_list_search_params(query, filter, &display_fields);
+    params.fields = None;
+    export_all_issues_json_native(&app, &params, &dest_path).await
+}
+
 /// Fetches checklist items for a given issue.
 #[tauri::command]
 async fn get_checklist(
@@ -2030,6 +4231,17 @@ async fn get_checklist(
     fetch_checklist_native(secrets_clone, &issue_key).await
 }
 
+/// Fetches the checklist and returns only its completion summary.
+#[tauri::command]
+async fn get_checklist_progress(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<bridge::ChecklistProgress, String> {
+    let secrets_clone = secrets.inner().clone();
+    let items = fetch_checklist_native(secrets_clone, &issue_key).await?;
+    Ok(checklist_progress(&items))
+}
+
 /// Adds a checklist item to an issue.
 #[tauri::command]
 async fn add_checklist_item(
@@ -2053,6 +4265,39 @@ async fn edit_checklist_item(
     edit_checklist_item_native(secrets_clone, &issue_key, &item_id, update).await
 }
 
+/// Marks several checklist items as done.
+#[tauri::command]
+async fn bulk_check_checklist_items(
+    issue_key: String,
+    item_ids: Vec<String>,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_checklist_items_checked_native(secrets_clone, &issue_key, &item_ids, true).await
+}
+
+/// Marks several checklist items as not done.
+#[tauri::command]
+async fn bulk_uncheck_checklist_items(
+    issue_key: String,
+    item_ids: Vec<String>,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_checklist_items_checked_native(secrets_clone, &issue_key, &item_ids, false).await
+}
+
+/// Appends an unchecked copy of an existing checklist item.
+#[tauri::command]
+async fn duplicate_checklist_item(
+    issue_key: String,
+    item_id: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    duplicate_checklist_item_native(secrets_clone, &issue_key, &item_id).await
+}
+
 /// Removes all checklist items from an issue.
 #[tauri::command]
 async fn delete_checklist(
@@ -2085,6 +4330,30 @@ async fn add_comment(
     add_comment_native(secrets_clone, &issue_key, &text).await
 }
 
+/// Adds an emoji reaction to an issue comment.
+#[tauri::command]
+async fn add_comment_reaction(
+    issue_key: String,
+    comment_id: String,
+    emoji: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    add_comment_reaction_native(secrets_clone, &issue_key, &comment_id, &emoji).await
+}
+
+/// Removes an emoji reaction from an issue comment.
+#[tauri::command]
+async fn remove_comment_reaction(
+    issue_key: String,
+    comment_id: String,
+    emoji: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    remove_comment_reaction_native(secrets_clone, &issue_key, &comment_id, &emoji).await
+}
+
 /// Updates editable issue fields such as summary/description.
 #[tauri::command]
 async fn update_issue(
@@ -2103,6 +4372,110 @@ async fn update_issue(
     .await
 }
 
+/// Returns raw issue fields outside the summary model (queue-specific custom fields).
+#[tauri::command]
+async fn get_issue_custom_fields(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<HashMap<String, Value>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_issue_custom_fields_native(secrets_clone, &issue_key).await
+}
+
+/// Sets a single issue field by key, typically a queue-specific custom field.
+#[tauri::command]
+async fn update_issue_custom_field(
+    issue_key: String,
+    field_key: String,
+    value: Value,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    update_issue_custom_field_native(secrets_clone, &issue_key, &field_key, value).await
+}
+
+/// Returns the issue's tags.
+#[tauri::command]
+async fn get_issue_tags(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<String>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_issue_tags_native(secrets_clone, &issue_key).await
+}
+
+/// Replaces the issue's whole tag list; returns the trimmed, deduplicated tags that were saved.
+#[tauri::command]
+async fn set_issue_tags(
+    issue_key: String,
+    tags: Vec<String>,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<String>, String> {
+    let secrets_clone = secrets.inner().clone();
+    set_issue_tags_native(secrets_clone, &issue_key, tags).await
+}
+
+/// Adds a tag while keeping the issue's other tags; returns the resulting tag list.
+#[tauri::command]
+async fn add_issue_tag(
+    issue_key: String,
+    tag: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<String>, String> {
+    let secrets_clone = secrets.inner().clone();
+    add_issue_tag_native(secrets_clone, &issue_key, &tag).await
+}
+
+/// Returns the number of votes an issue has.
+#[tauri::command]
+async fn get_issue_votes(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<u64, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_issue_votes_native(secrets_clone, &issue_key).await
+}
+
+/// Votes for an issue as the current user.
+#[tauri::command]
+async fn vote_for_issue(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_issue_vote_native(secrets_clone, &issue_key, true).await
+}
+
+/// Withdraws the current user's vote from an issue.
+#[tauri::command]
+async fn unvote_issue(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_issue_vote_native(secrets_clone, &issue_key, false).await
+}
+
+/// Subscribes the current user to issue notifications; followers set on the issue are unchanged.
+#[tauri::command]
+async fn subscribe_to_issue(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_issue_subscription_native(secrets_clone, &issue_key, true).await
+}
+
+/// Removes the current user's notification subscription from an issue.
+#[tauri::command]
+async fn unsubscribe_from_issue(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    set_issue_subscription_native(secrets_clone, &issue_key, false).await
+}
+
 /// Fetches attachment metadata for an issue.
 #[tauri::command]
 async fn get_attachments(
@@ -2153,70 +4526,151 @@ async fn upload_temp_attachment(
         .first()
         .map(|m| m.to_string());
     let secrets_clone = secrets.inner().clone();
-    upload_temp_attachment_native(secrets_clone, &file_name, file_bytes, mime_type.as_deref()).await
+    upload_temp_attachment_native(secrets_clone, &file_name, file_bytes, mime_type.as_deref()).await
+}
+
+/// Returns catalog of Tracker statuses for filters/forms.
+#[tauri::command]
+async fn get_statuses(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_statuses_native(secrets_clone).await
+}
+
+/// Returns catalog of Tracker resolutions for filters/forms.
+#[tauri::command]
+async fn get_resolutions(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_resolutions_native(secrets_clone).await
+}
+
+/// Returns available Tracker queues.
+#[tauri::command]
+async fn get_queues(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_queues_native(secrets_clone).await
+}
+
+/// Returns available Tracker projects.
+#[tauri::command]
+async fn get_projects(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_projects_native(secrets_clone).await
+}
+
+/// Returns user directory entries for assignment/filtering.
+#[tauri::command]
+async fn get_users(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::UserProfile>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_users_native(secrets_clone).await
+}
+
+/// Returns catalog of Tracker priorities for filters/forms.
+#[tauri::command]
+async fn get_priorities(
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_priorities_native(secrets_clone).await
+}
+
+/// Returns catalog of Tracker issue types for filters/forms, optionally limited to a queue.
+#[tauri::command]
+async fn get_issue_types(
+    secrets: tauri::State<'_, SecretsManager>,
+    queue_key: Option<String>,
+) -> Result<Vec<bridge::SimpleEntity>, String> {
+    let secrets_clone = secrets.inner().clone();
+    let queue_key = normalize_owned_string(queue_key);
+    fetch_issue_types_native(secrets_clone, queue_key.as_deref()).await
 }
 
-/// Returns catalog of Tracker statuses for filters/forms.
+/// Returns field definitions for a queue to drive dynamic issue forms.
 #[tauri::command]
-async fn get_statuses(
+async fn get_queue_fields(
+    queue_key: String,
     secrets: tauri::State<'_, SecretsManager>,
-) -> Result<Vec<bridge::SimpleEntity>, String> {
+) -> Result<Vec<bridge::FieldDefinition>, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_statuses_native(secrets_clone).await
+    fetch_queue_fields_native(secrets_clone, &queue_key).await
 }
 
-/// Returns catalog of Tracker resolutions for filters/forms.
+/// Returns queue details (description, lead, default issue type) for the queue detail view.
 #[tauri::command]
-async fn get_resolutions(
+async fn get_queue(
+    queue_key: String,
     secrets: tauri::State<'_, SecretsManager>,
-) -> Result<Vec<bridge::SimpleEntity>, String> {
+) -> Result<bridge::Queue, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_resolutions_native(secrets_clone).await
+    fetch_queue_native(secrets_clone, &queue_key).await
 }
 
-/// Returns available Tracker queues.
+/// Returns issue types a queue accepts, so issue forms only offer valid types.
 #[tauri::command]
-async fn get_queues(
+async fn get_queue_issue_types(
+    queue_key: String,
     secrets: tauri::State<'_, SecretsManager>,
 ) -> Result<Vec<bridge::SimpleEntity>, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_queues_native(secrets_clone).await
+    fetch_queue_issue_types_native(secrets_clone, &queue_key).await
 }
 
-/// Returns available Tracker projects.
+/// Returns components of a queue for filters and issue forms.
 #[tauri::command]
-async fn get_projects(
+async fn get_queue_components(
+    queue_key: String,
     secrets: tauri::State<'_, SecretsManager>,
 ) -> Result<Vec<bridge::SimpleEntity>, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_projects_native(secrets_clone).await
+    fetch_queue_components_native(secrets_clone, &queue_key).await
 }
 
-/// Returns user directory entries for assignment/filtering.
+/// Applies the same field patch (e.g. assignee or priority) to several issues at once.
 #[tauri::command]
-async fn get_users(
+async fn bulk_update_issues(
+    keys: Vec<String>,
+    patch: Value,
     secrets: tauri::State<'_, SecretsManager>,
-) -> Result<Vec<bridge::UserProfile>, String> {
+) -> Result<bridge::BulkChangeResult, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_users_native(secrets_clone).await
+    bulk_update_issues_native(secrets_clone, &keys, &patch).await
 }
 
-/// Returns catalog of Tracker priorities for filters/forms.
+/// Moves an issue to another queue and drops its stale key from the issue cache.
 #[tauri::command]
-async fn get_priorities(
+async fn move_issue_to_queue(
+    issue_key: String,
+    target_queue_key: String,
     secrets: tauri::State<'_, SecretsManager>,
-) -> Result<Vec<bridge::SimpleEntity>, String> {
+    issue_store: tauri::State<'_, IssueStore>,
+) -> Result<(), String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_priorities_native(secrets_clone).await
+    let queue_changed =
+        move_issue_to_queue_native(secrets_clone, &issue_key, &target_queue_key).await?;
+    if queue_changed {
+        issue_store.remove_one(&issue_key);
+    }
+    Ok(())
 }
 
-/// Returns catalog of Tracker issue types for filters/forms.
+/// Creates a copy of an issue; only summary, description, type, priority and queue are copied.
 #[tauri::command]
-async fn get_issue_types(
+async fn clone_issue(
+    source_key: String,
+    new_summary: Option<String>,
     secrets: tauri::State<'_, SecretsManager>,
-) -> Result<Vec<bridge::SimpleEntity>, String> {
+) -> Result<bridge::Issue, String> {
     let secrets_clone = secrets.inner().clone();
-    fetch_issue_types_native(secrets_clone).await
+    clone_issue_native(secrets_clone, &source_key, new_summary.as_deref()).await
 }
 
 /// Creates a new issue in the specified queue.
@@ -2288,6 +4742,15 @@ async fn release_scroll_context(app: tauri::AppHandle, scroll_id: String) -> Res
     release_scroll_context_native(&app, &scroll_id).await
 }
 
+/// Releases several stale scroll contexts at once, e.g. when a view with multiple searches unmounts.
+#[tauri::command]
+async fn release_scroll_contexts(
+    app: tauri::AppHandle,
+    scroll_ids: Vec<String>,
+) -> Result<(), String> {
+    release_scroll_contexts_native(&app, &scroll_ids).await
+}
+
 /// Downloads an attachment to a selected local filesystem destination.
 #[tauri::command]
 async fn download_attachment(
@@ -2331,6 +4794,16 @@ async fn get_transitions(
     fetch_transitions_native(secrets_clone, &issue_key).await
 }
 
+/// Fetches available transitions with their screen fields for dynamic transition forms.
+#[tauri::command]
+async fn get_transitions_with_fields(
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<Vec<bridge::TransitionWithFields>, String> {
+    let secrets_clone = secrets.inner().clone();
+    fetch_transitions_with_fields_native(secrets_clone, &issue_key).await
+}
+
 /// Executes a workflow transition for an issue with optional metadata.
 #[tauri::command]
 async fn execute_transition(
@@ -2351,6 +4824,31 @@ async fn execute_transition(
     .await
 }
 
+/// Executes a workflow transition sending transition screen fields as a JSON object.
+#[tauri::command]
+async fn execute_transition_with_fields(
+    issue_key: String,
+    transition_id: String,
+    fields: Value,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    execute_transition_with_fields_native(secrets_clone, &issue_key, &transition_id, fields).await
+}
+
+/// Moves an issue to a done status and emits `issue-closed` on success.
+#[tauri::command]
+async fn mark_issue_done(
+    app: tauri::AppHandle,
+    issue_key: String,
+    secrets: tauri::State<'_, SecretsManager>,
+) -> Result<(), String> {
+    let secrets_clone = secrets.inner().clone();
+    mark_issue_done_native(secrets_clone, &issue_key).await?;
+    emit_issue_closed_event(&app, &issue_key);
+    Ok(())
+}
+
 /// Starts local timer and emits updated timer state to frontend listeners.
 #[tauri::command]
 fn start_timer(
@@ -2359,27 +4857,133 @@ fn start_timer(
     issue_store: tauri::State<'_, IssueStore>,
     issue_key: String,
     issue_summary: Option<String>,
+    estimated_seconds: Option<u64>,
 ) {
-    timer.start(issue_key, issue_summary);
+    issue_store.update_last_used(&issue_key);
+    timer.start_with_estimate(issue_key, issue_summary, estimated_seconds);
     broadcast_timer_state(&app, &timer, issue_store.inner());
 }
 
 /// Stops local timer, returns elapsed data, and emits final timer state.
+///
+/// When auto-logging is enabled in config, the elapsed time is submitted as a
+/// worklog in the background; the third tuple element reports whether that
+/// submission started so the frontend can skip its manual worklog dialog.
 #[tauri::command]
 fn stop_timer(
     app: tauri::AppHandle,
     timer: tauri::State<'_, Arc<Timer>>,
     issue_store: tauri::State<'_, IssueStore>,
-) -> (u64, Option<String>) {
-    let result = timer.stop();
+) -> (u64, Option<String>, bool) {
+    let (elapsed, issue_key) = timer.stop();
+    broadcast_timer_state(&app, &timer, issue_store.inner());
+    let auto_logged = issue_key
+        .as_deref()
+        .is_some_and(|key| auto_log_stopped_timer(&app, key, elapsed));
+    (elapsed, issue_key, auto_logged)
+}
+
+/// Shifts the running timer start `offset_minutes` into the past and re-emits `timer-tick`.
+#[tauri::command]
+fn adjust_timer_start(
+    app: tauri::AppHandle,
+    timer: tauri::State<'_, Arc<Timer>>,
+    issue_store: tauri::State<'_, IssueStore>,
+    offset_minutes: i64,
+) -> Result<(), String> {
+    timer
+        .adjust_start_time(offset_minutes.saturating_mul(60))
+        .map_err(str::to_string)?;
     broadcast_timer_state(&app, &timer, issue_store.inner());
-    result
+    Ok(())
 }
 
 /// Returns current timer state snapshot.
 #[tauri::command]
-fn get_timer_state(state: tauri::State<Arc<Timer>>) -> timer::TimerState {
-    state.get_state()
+fn get_timer_state(
+    state: tauri::State<Arc<Timer>>,
+    issue_store: tauri::State<'_, IssueStore>,
+) -> timer::TimerState {
+    timer_snapshot(&state, issue_store.inner())
+}
+
+/// Returns cached issue counts grouped by status key; no API call is made.
+#[tauri::command]
+fn get_issue_status_counts(issue_store: tauri::State<'_, IssueStore>) -> HashMap<String, usize> {
+    issue_store.count_by_status()
+}
+
+/// Returns cached issues in the given status; no API call is made.
+#[tauri::command]
+fn find_cached_issues_by_status(
+    issue_store: tauri::State<'_, IssueStore>,
+    status_key: String,
+) -> Vec<bridge::Issue> {
+    issue_store.find_by_status(status_key.trim())
+}
+
+/// Returns cached issues assigned to a user by display name; no API call is made.
+#[tauri::command]
+fn find_cached_issues_by_assignee(
+    issue_store: tauri::State<'_, IssueStore>,
+    assignee_display: String,
+) -> Vec<bridge::Issue> {
+    issue_store.find_by_assignee(&assignee_display)
+}
+
+/// Returns the cached issues of a named saved-query store; unknown names yield an empty list.
+#[tauri::command]
+fn get_named_issue_cache(
+    registry: tauri::State<'_, IssueStoreRegistry>,
+    name: String,
+) -> Vec<bridge::Issue> {
+    registry
+        .get(name.trim())
+        .map(|store| store.snapshot())
+        .unwrap_or_default()
+}
+
+/// Runs a query and stores the result under the given saved-query name.
+#[tauri::command]
+async fn refresh_named_cache(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, IssueStoreRegistry>,
+    timer: tauri::State<'_, Arc<Timer>>,
+    name: String,
+    query: String,
+) -> Result<Vec<bridge::Issue>, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Cache name must not be empty".to_string());
+    }
+    let query = query.trim();
+    if query.is_empty() {
+        return Err("Query must not be empty".to_string());
+    }
+
+    let store = registry.get_or_create(name);
+    if name == DEFAULT_STORE_NAME {
+        // The default store also drives the tray menu.
+        return refresh_issue_cache(app, store, timer.inner().clone(), Some(query.to_string()))
+            .await;
+    }
+
+    let params = IssueSearchParams::new(Some(query.to_string()), None);
+    let issues = fetch_issues_native(&app, &params).await?;
+    store.set(issues.clone());
+    Ok(issues)
+}
+
+/// Returns recently completed timer sessions, most recent first.
+#[tauri::command]
+fn get_timer_session_log(state: tauri::State<Arc<Timer>>) -> Vec<timer::CompletedSession> {
+    state.session_log()
+}
+
+/// Forgets all completed timer sessions.
+#[tauri::command]
+fn clear_timer_session_log(state: tauri::State<Arc<Timer>>) {
+    state.clear_session_log();
 }
 
 /// Emits normalized updater-available payload to frontend listeners.
@@ -2417,6 +5021,12 @@ pub fn run() {
     .try_init();
 
     info!("Starting YTracker native runtime");
+    if !EXTRA_SENSITIVE_LOG_HINTS.is_empty() {
+        debug!(
+            "Loaded {} extra sensitive log hints",
+            EXTRA_SENSITIVE_LOG_HINTS.len()
+        );
+    }
 
     let timer = Arc::new(Timer::new());
     let timer_for_thread = timer.clone();
@@ -2430,6 +5040,9 @@ pub fn run() {
     let issue_store_for_thread_loop = issue_store.clone();
     let issue_store_for_refresh_loop = issue_store.clone();
 
+    let config_cache = ConfigCache::new(normalize_config(ConfigManager::new().load()));
+    let config_cache_for_setup = config_cache.clone();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_notification::init())
@@ -2438,11 +5051,37 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(timer.clone())
         .manage(issue_store.clone())
+        .manage(IssueStoreRegistry::new(issue_store.clone()))
+        .manage(ScrollContextRegistry::default())
+        .manage(PrefetchedPages::<IssuePagePayload>::default())
+        .manage(config_cache)
         .setup(move |app| {
             let app_handle = app.handle();
             let secrets_manager = SecretsManager::initialize(&app_handle)?;
+            secrets_manager.set_proxy_settings(proxy_settings(&config_cache_for_setup.get()));
+            let secrets_for_config = secrets_manager.clone();
             app.manage(secrets_manager);
 
+            let config_watcher = ConfigManager::with_app_handle(app_handle.clone());
+            if let Err(err) = config_watcher.watch(normalize_config) {
+                warn!("Failed to watch config file: {}", err);
+            }
+            // Saves, resets and external edits all announce the new config, so the cache
+            // stays current without reading the file on every tray refresh or reminder tick.
+            for event_name in ["config-changed", "config-reset"] {
+                let listener_cache = config_cache_for_setup.clone();
+                let listener_secrets = secrets_for_config.clone();
+                app_handle.listen(event_name, move |event| {
+                    match serde_json::from_str::<Config>(event.payload()) {
+                        Ok(config) => {
+                            listener_secrets.set_proxy_settings(proxy_settings(&config));
+                            listener_cache.set(config);
+                        }
+                        Err(err) => warn!("Ignoring malformed {} payload: {}", event_name, err),
+                    }
+                });
+            }
+
             let startup_update_handle = app_handle.clone();
             tauri::async_runtime::spawn(async move {
                 if let Err(err) = check_for_updates_and_emit(startup_update_handle, true).await {
@@ -2451,7 +5090,15 @@ pub fn run() {
             });
             let initial_issues = issue_store_for_setup.snapshot();
             let initial_state = timer_for_tray_setup.get_state();
-            let initial_menu = build_tray_menu(&app_handle, &initial_issues, &initial_state)?;
+            let initial_config = config_cache_for_setup.get();
+            let initial_tz = parse_workday_timezone(initial_config.workday_timezone.as_deref());
+            let initial_menu = build_tray_menu(
+                &app_handle,
+                &initial_issues,
+                &initial_state,
+                count_tray_overdue_issues(&initial_issues, initial_tz),
+                initial_config.tray_group_by_queue,
+            )?;
 
             let tray_timer = timer_for_tray_events.clone();
             let tray_issue_store = issue_store_for_events.clone();
@@ -2486,16 +5133,29 @@ pub fn run() {
                         let (elapsed, maybe_key) = tray_timer.stop();
                         broadcast_timer_state(app, &tray_timer, &tray_issue_store);
                         if let Some(issue_key) = maybe_key.as_deref() {
-                            if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.show();
-                                let _ = window.set_focus();
+                            if !auto_log_stopped_timer(app, issue_key, elapsed) {
+                                if let Some(window) = app.get_webview_window("main") {
+                                    let _ = window.show();
+                                    let _ = window.set_focus();
+                                }
+                                emit_timer_stopped_event(app, issue_key, elapsed);
                             }
-                            emit_timer_stopped_event(app, issue_key, elapsed);
                             notify_timer_stopped(app, issue_key, elapsed);
                         }
                     }
-                    id if id.starts_with(ISSUE_MENU_PREFIX) => {
-                        let issue_key = &id[ISSUE_MENU_PREFIX.len()..];
+                    id if id.starts_with(DONE_MENU_PREFIX) => {
+                        let issue_key = id[DONE_MENU_PREFIX.len()..].to_string();
+                        let app_handle = app.clone();
+                        let issue_store = tray_issue_store.clone();
+                        let timer = tray_timer.clone();
+                        tauri::async_runtime::spawn(async move {
+                            close_issue_from_tray(app_handle, issue_store, timer, issue_key).await;
+                        });
+                    }
+                    id => {
+                        let Some(issue_key) = tray_menu_issue_key(id) else {
+                            return;
+                        };
                         let current_state = tray_timer.get_state();
                         if current_state.issue_key.as_deref() == Some(issue_key) {
                             return;
@@ -2503,10 +5163,10 @@ pub fn run() {
 
                         let summary = tray_issue_store.find(issue_key).map(|issue| issue.summary);
                         tray_timer.start(issue_key.to_string(), summary.clone());
+                        tray_issue_store.update_last_used(issue_key);
                         broadcast_timer_state(app, &tray_timer, &tray_issue_store);
                         notify_timer_started(app, issue_key, summary.as_deref());
                     }
-                    _ => {}
                 })
                 .build(app)?;
 
@@ -2529,6 +5189,25 @@ pub fn run() {
                             {
                                 warn!("Background issue refresh failed");
                                 debug!("Background refresh details: {}", redact_log_details(&err));
+                            } else if let Err(err) = refresh_today_logged_seconds(
+                                &refresh_app_handle,
+                                &refresh_issue_store,
+                            )
+                            .await
+                            {
+                                debug!(
+                                    "Today logged total refresh skipped: {}",
+                                    redact_log_details(&err)
+                                );
+                            } else {
+                                let state = refresh_timer.get_state();
+                                if let Err(err) = update_tray_menu(
+                                    &refresh_app_handle,
+                                    &refresh_issue_store.snapshot(),
+                                    &state,
+                                ) {
+                                    warn!("Failed to update tray state: {}", err);
+                                }
                             }
                         }
                         Ok(false) => {}
@@ -2540,16 +5219,42 @@ pub fn run() {
                 }
             });
 
+            let sweep_app_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                let interval = std::time::Duration::from_secs(SCROLL_CONTEXT_SWEEP_INTERVAL_SECS);
+                let ttl = std::time::Duration::from_millis(ISSUE_SCROLL_TTL_MILLIS);
+                loop {
+                    sleep(interval).await;
+                    sweep_app_handle
+                        .state::<PrefetchedPages<IssuePagePayload>>()
+                        .purge_expired(ttl);
+                    let expired = sweep_app_handle
+                        .state::<ScrollContextRegistry>()
+                        .take_expired(ttl);
+                    for scroll_id in expired {
+                        if let Err(err) =
+                            release_scroll_context_native(&sweep_app_handle, &scroll_id).await
+                        {
+                            debug!(
+                                "Stale scroll context release skipped: {}",
+                                redact_log_details(&err)
+                            );
+                        }
+                    }
+                }
+            });
+
             let event_handle = app_handle.clone();
             let notification_handle = app_handle.clone();
             let tray_update_handle = app_handle.clone();
             let thread_issue_store = issue_store_for_thread_loop.clone();
+            let thread_config = config_cache_for_setup.clone();
             std::thread::spawn(move || {
-                let config_manager = ConfigManager::new();
                 let mut last_workday_notification_day: Option<String> = None;
+                let mut last_start_notification_day: Option<String> = None;
                 loop {
                     std::thread::sleep(std::time::Duration::from_secs(60));
-                    let state = timer_for_thread.get_state();
+                    let state = timer_snapshot(&timer_for_thread, &thread_issue_store);
                     if state.active {
                         let _ = event_handle.emit("timer-tick", &state);
                         if let Err(err) = update_tray_menu(
@@ -2561,7 +5266,7 @@ pub fn run() {
                         }
                     }
 
-                    let runtime_config = config_manager.load();
+                    let runtime_config = thread_config.get();
                     let interval_minutes = runtime_config.timer_notification_interval.max(1);
                     if let Some(snapshot) =
                         timer_for_thread.check_notification_due(interval_minutes as u64 * 60)
@@ -2591,9 +5296,69 @@ pub fn run() {
                         }
                     }
 
-                    let now = Local::now();
+                    if let Some(snapshot) = timer_for_thread.check_estimate_overflow() {
+                        let issue_key = snapshot.issue_key.as_deref().unwrap_or("the current issue");
+                        if let Err(err) = notification_handle
+                            .notification()
+                            .builder()
+                            .title("Estimate exceeded")
+                            .body(format!("You have exceeded the estimate for {}", issue_key))
+                            .show()
+                        {
+                            warn!("Failed to show estimate overflow notification: {}", err);
+                        }
+                    }
+
+                    if runtime_config.break_reminder_enabled
+                        && runtime_config.break_reminder_minutes > 0
+                    {
+                        let break_interval = u64::from(runtime_config.break_reminder_minutes) * 60;
+                        if let Some(snapshot) =
+                            timer_for_thread.check_break_reminder_due(break_interval)
+                        {
+                            let body = format!(
+                                "You've been working for {} — consider taking a break.",
+                                format_elapsed(snapshot.elapsed)
+                            );
+                            if let Err(err) = notification_handle
+                                .notification()
+                                .builder()
+                                .title("Time for a break")
+                                .body(body)
+                                .show()
+                            {
+                                warn!("Failed to show break reminder: {}", err);
+                            }
+                        }
+                    }
+
+                    let tz = parse_workday_timezone(runtime_config.workday_timezone.as_deref());
+                    let now = workday_now(tz);
                     let today_key = now.format("%Y-%m-%d").to_string();
                     let end_time = parse_workday_time(&runtime_config.workday_end_time);
+
+                    if runtime_config.workday_start_reminder
+                        && last_start_notification_day.as_deref() != Some(today_key.as_str())
+                        && should_remind_workday_start(
+                            now.time(),
+                            parse_workday_time(&runtime_config.workday_start_time),
+                            end_time,
+                            state.active,
+                            state.total_today_seconds,
+                        )
+                    {
+                        last_start_notification_day = Some(today_key.clone());
+                        if let Err(err) = notification_handle
+                            .notification()
+                            .builder()
+                            .title("Good morning")
+                            .body(WORKDAY_START_REMINDER_TEXT)
+                            .show()
+                        {
+                            warn!("Failed to show start-of-workday notification: {}", err);
+                        }
+                    }
+
                     let already_notified_today =
                         last_workday_notification_day.as_deref() == Some(today_key.as_str());
 
@@ -2607,12 +5372,14 @@ pub fn run() {
                         let active_elapsed_seconds = if state.active { state.elapsed } else { 0 };
                         let expected_seconds = u64::from(runtime_config.workday_hours) * 3600;
                         let workday_hours = sanitize_workday_hours(runtime_config.workday_hours);
+                        let motivation = select_motivational_phrase(&runtime_config);
 
                         tauri::async_runtime::spawn(async move {
                             let logged_seconds = match fetch_today_logged_seconds_for_issues(
                                 &app_for_workday_notification,
                                 &issues_snapshot,
                                 workday_hours,
+                                tz,
                             )
                             .await
                             {
@@ -2635,7 +5402,7 @@ pub fn run() {
                                         "Tracked {} of {} today. {}",
                                         format_elapsed(tracked_total),
                                         format_elapsed(expected_seconds),
-                                        motivational_phrase()
+                                        motivation
                                     ),
                                 )
                             } else {
@@ -2673,19 +5440,50 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             get_issues,
+            fulltext_search_issues,
+            get_issue_count,
             get_issue,
+            get_parent_issue,
+            get_issue_linked_issues,
+            open_issue_in_browser,
+            get_issue_changelog,
             get_issue_worklogs,
+            get_issue_worklogs_in_range,
             get_today_logged_seconds_for_issues,
+            get_daily_worklog_breakdown,
+            get_weekly_summary,
+            export_worklogs_csv,
+            export_all_issues_json,
             get_checklist,
+            get_checklist_progress,
             add_checklist_item,
             edit_checklist_item,
+            duplicate_checklist_item,
+            bulk_check_checklist_items,
+            bulk_uncheck_checklist_items,
             delete_checklist,
             delete_checklist_item,
             get_comments,
+            get_comment,
             add_comment,
+            add_comment_reaction,
+            remove_comment_reaction,
             update_issue,
             update_issue_extended,
+            get_issue_custom_fields,
+            update_issue_custom_field,
+            get_issue_tags,
+            set_issue_tags,
+            add_issue_tag,
+            get_issue_votes,
+            vote_for_issue,
+            unvote_issue,
+            subscribe_to_issue,
+            unsubscribe_from_issue,
             create_issue,
+            clone_issue,
+            move_issue_to_queue,
+            bulk_update_issues,
             get_attachments,
             upload_attachment,
             upload_temp_attachment,
@@ -2696,20 +5494,46 @@ pub fn run() {
             get_users,
             get_priorities,
             get_issue_types,
+            get_queue_fields,
+            get_queue,
+            get_queue_issue_types,
+            get_queue_components,
             release_scroll_context,
+            release_scroll_contexts,
+            prefetch_next_issue_page,
             download_attachment,
             preview_attachment,
             preview_inline_image,
             get_transitions,
+            get_transitions_with_fields,
             execute_transition,
+            execute_transition_with_fields,
+            mark_issue_done,
             start_timer,
             stop_timer,
             get_timer_state,
+            adjust_timer_start,
+            get_timer_session_log,
+            clear_timer_session_log,
+            get_issue_status_counts,
+            find_cached_issues_by_status,
+            find_cached_issues_by_assignee,
+            get_named_issue_cache,
+            refresh_named_cache,
             get_config,
             save_config,
+            reset_config,
+            export_config,
+            import_config,
+            import_config_from_file,
             get_client_credentials_info,
+            save_client_credentials,
+            get_rate_limiter_metrics,
+            check_connectivity,
+            get_version_info,
             has_session,
             exchange_code,
+            generate_pkce_pair,
             log_work,
             get_current_user,
             logout
@@ -2717,3 +5541,425 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bridge, check_preview_size, checklist_progress, coerce_comment_author,
+        coerce_display_value, coerce_display_value_array, collapse_whitespace,
+        count_overdue_issues, elapsed_to_duration_input, format_elapsed, format_elapsed_precise,
+        format_scroll_id, format_tray_tooltip, group_issues_by_queue, is_me_token,
+        mime_from_extension, normalize_issue_display_fields, normalize_issue_tags,
+        parse_duration_to_iso, parse_duration_value_to_seconds, parse_sensitive_log_hints,
+        parse_tracker_duration_to_seconds, resolve_download_destination, select_done_transition,
+        should_remind_workday_start, timer, truncate_text, truncate_text_at_word,
+        validate_filter_map, validate_reaction_emoji, NativeCommentAuthor, NativeTransition,
+    };
+    use chrono::{NaiveDate, NaiveTime};
+    use directories::UserDirs;
+    use serde_json::{json, Value};
+    use std::path::PathBuf;
+
+    fn issue_with_deadline(key: &str, deadline: Option<&str>) -> bridge::Issue {
+        bridge::Issue {
+            key: key.to_string(),
+            summary: String::new(),
+            description: String::new(),
+            status: bridge::Status {
+                key: "open".to_string(),
+                display: "Open".to_string(),
+            },
+            priority: bridge::Priority {
+                key: "normal".to_string(),
+                display: "Normal".to_string(),
+            },
+            issue_type: None,
+            assignee: None,
+            tags: Vec::new(),
+            components: Vec::new(),
+            followers: Vec::new(),
+            tracked_seconds: None,
+            parent_key: None,
+            parent_summary: None,
+            deadline: deadline.map(str::to_string),
+            votes: None,
+        }
+    }
+
+    #[test]
+    fn check_preview_size_rejects_only_known_oversized_attachments() {
+        let limit = 8 * 1024 * 1024;
+        assert_eq!(check_preview_size(Some(limit), limit), Ok(()));
+        assert_eq!(check_preview_size(None, limit), Ok(()));
+        assert_eq!(
+            check_preview_size(Some(50 * 1024 * 1024), limit),
+            Err(
+                "Attachment too large for preview (50.0 MB, limit 8.0 MB); download it instead"
+                    .to_string()
+            )
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_download_destination_expands_home_directory() {
+        let home = UserDirs::new()
+            .map(|dirs| dirs.home_dir().to_path_buf())
+            .expect("home directory should be known");
+        assert_eq!(
+            resolve_download_destination(" ~/Downloads/foo.pdf "),
+            Ok(home.join("Downloads").join("foo.pdf"))
+        );
+        assert_eq!(
+            resolve_download_destination("/tmp/~/foo.pdf"),
+            Ok(PathBuf::from("/tmp/~/foo.pdf"))
+        );
+    }
+
+    #[test]
+    fn mime_from_extension_covers_common_attachment_types() {
+        assert_eq!(mime_from_extension("report.pdf"), Some("application/pdf"));
+        assert_eq!(mime_from_extension("Screenshot.PNG"), Some("image/png"));
+        assert_eq!(mime_from_extension("photo.jpg"), Some("image/jpeg"));
+        assert_eq!(
+            mime_from_extension("budget.xlsx"),
+            Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+        );
+        assert_eq!(
+            mime_from_extension("archive.tar.gz"),
+            Some("application/gzip")
+        );
+        assert_eq!(mime_from_extension("README"), None);
+        assert_eq!(mime_from_extension("notes.unknownext"), None);
+    }
+
+    #[test]
+    fn normalize_issue_tags_trims_and_drops_duplicates() {
+        let tags = vec![
+            " backend ".to_string(),
+            "urgent".to_string(),
+            "".to_string(),
+            "backend".to_string(),
+            "Backend".to_string(),
+        ];
+        assert_eq!(
+            normalize_issue_tags(tags),
+            vec!["backend", "urgent", "Backend"]
+        );
+    }
+
+    #[test]
+    fn count_overdue_issues_counts_only_past_deadlines() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 10).expect("valid date");
+        let issues = vec![
+            issue_with_deadline("YT-1", Some("2024-05-09")),
+            issue_with_deadline("YT-2", Some("2024-05-10")),
+            issue_with_deadline("YT-3", Some("2024-06-01")),
+            issue_with_deadline("YT-4", None),
+            issue_with_deadline("YT-5", Some("not-a-date")),
+        ];
+
+        assert_eq!(count_overdue_issues(&issues, today), 1);
+    }
+
+    #[test]
+    fn format_tray_tooltip_reports_today_total_and_active_timer() {
+        let idle = timer::TimerState {
+            active: false,
+            issue_key: None,
+            issue_summary: None,
+            start_time: None,
+            elapsed: 0,
+            total_today_seconds: 0,
+            estimated_seconds: None,
+        };
+        assert_eq!(format_tray_tooltip(&idle, 3900, 0), "Today: 1h 05m logged");
+        assert_eq!(
+            format_tray_tooltip(&idle, 3900, 1),
+            "Today: 1h 05m logged / 1 issue"
+        );
+
+        let running = timer::TimerState {
+            active: true,
+            issue_key: Some("YT-1".to_string()),
+            issue_summary: None,
+            start_time: Some(0),
+            elapsed: 1800,
+            total_today_seconds: 7200,
+            estimated_seconds: None,
+        };
+        assert_eq!(
+            format_tray_tooltip(&running, 7200, 12),
+            "Tracking: YT-1 0h 30m / Today: 2h 00m / 12 issues"
+        );
+    }
+
+    #[test]
+    fn select_done_transition_prefers_closed_over_resolved() {
+        let transitions: Vec<NativeTransition> = serde_json::from_value(json!([
+            {"id": "start", "to": {"key": "inProgress"}},
+            {"id": "resolve", "to": {"key": "resolved"}},
+            {"id": "close", "to": {"key": "closed"}}
+        ]))
+        .expect("valid transitions");
+
+        assert_eq!(
+            select_done_transition(&transitions),
+            Some(("close".to_string(), "closed".to_string()))
+        );
+        assert_eq!(select_done_transition(&transitions[..1]), None);
+    }
+
+    #[test]
+    fn should_remind_workday_start_only_inside_idle_working_hours() {
+        let at = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).expect("valid time");
+        let start = Some(at(9, 0));
+        let end = Some(at(17, 0));
+
+        assert!(should_remind_workday_start(at(9, 5), start, end, false, 0));
+        assert!(!should_remind_workday_start(at(8, 59), start, end, false, 0));
+        assert!(!should_remind_workday_start(at(17, 30), start, end, false, 0));
+        assert!(!should_remind_workday_start(at(10, 0), start, end, true, 0));
+        assert!(!should_remind_workday_start(at(10, 0), start, end, false, 600));
+        assert!(!should_remind_workday_start(at(10, 0), None, end, false, 0));
+    }
+
+    #[test]
+    fn group_issues_by_queue_keeps_first_appearance_order() {
+        let issues = vec![
+            issue_with_deadline("FOO-1", None),
+            issue_with_deadline("BAR-7", None),
+            issue_with_deadline("FOO-2", None),
+        ];
+
+        let groups = group_issues_by_queue(&issues);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(queue, members)| (*queue, members.iter().map(|issue| issue.key.as_str()).collect()))
+            .collect();
+        assert_eq!(summary, vec![("FOO", vec!["FOO-1", "FOO-2"]), ("BAR", vec!["BAR-7"])]);
+    }
+
+    #[test]
+    fn validate_reaction_emoji_rejects_text_and_empty_values() {
+        assert!(validate_reaction_emoji("\u{1F44D}").is_ok());
+        assert!(validate_reaction_emoji("\u{2764}\u{FE0F}").is_ok());
+        assert!(validate_reaction_emoji("1\u{FE0F}\u{20E3}").is_ok());
+        assert!(validate_reaction_emoji("#\u{FE0F}\u{20E3}").is_ok());
+        assert!(validate_reaction_emoji("#1").is_err());
+        assert!(validate_reaction_emoji("").is_err());
+        assert!(validate_reaction_emoji("like").is_err());
+        assert!(validate_reaction_emoji("\u{1F44D} ").is_err());
+    }
+
+    #[test]
+    fn normalize_issue_display_fields_keeps_key_and_summary_first() {
+        let fields = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+
+        assert!(normalize_issue_display_fields(Vec::new()).is_empty());
+        assert_eq!(
+            normalize_issue_display_fields(fields(&[" status", "key", "", "status", "deadline"])),
+            vec!["key", "summary", "status", "deadline"]
+        );
+    }
+
+    #[test]
+    fn checklist_progress_rounds_percent_down() {
+        let item = |id: &str, checked: bool| bridge::ChecklistItem {
+            id: id.to_string(),
+            text: format!("Item {id}"),
+            checked,
+            assignee: None,
+            deadline: None,
+            deadline_type: None,
+            is_exceeded: None,
+            item_type: None,
+        };
+        let items = vec![item("1", true), item("2", true), item("3", false)];
+
+        let progress = checklist_progress(&items);
+        assert_eq!(
+            progress,
+            bridge::ChecklistProgress {
+                total: 3,
+                checked: 2,
+                percent: 66,
+            }
+        );
+        assert_eq!(checklist_progress(&[]).percent, 0);
+    }
+
+    #[test]
+    fn collapse_whitespace_joins_words_with_single_spaces() {
+        assert_eq!(collapse_whitespace("  Fix   login  "), "Fix login");
+        assert_eq!(collapse_whitespace("Fix\tlogin\n\npage"), "Fix login page");
+        assert_eq!(collapse_whitespace(" \t\n "), "");
+    }
+
+    #[test]
+    fn truncate_text_cuts_by_characters_with_ellipsis() {
+        assert_eq!(truncate_text("abcde", 5), "abcde");
+        assert_eq!(truncate_text("  abc  ", 5), "abc");
+        assert_eq!(truncate_text("abcdef", 5), "abcd…");
+        assert_eq!(truncate_text("abcdef", 1), "…");
+        assert_eq!(truncate_text("Привет, мир", 7), "Привет…");
+    }
+
+    #[test]
+    fn format_elapsed_rounds_down_to_minutes() {
+        assert_eq!(format_elapsed(0), "0m");
+        assert_eq!(format_elapsed(59), "0m");
+        assert_eq!(format_elapsed(60), "1m");
+        assert_eq!(format_elapsed(3599), "59m");
+        assert_eq!(format_elapsed(3600), "1h 00m");
+        assert_eq!(format_elapsed(7265), "2h 01m");
+    }
+
+    #[test]
+    fn truncate_text_at_word_breaks_on_whitespace() {
+        assert_eq!(
+            truncate_text_at_word("Fix login page", 20),
+            "Fix login page"
+        );
+        assert_eq!(
+            truncate_text_at_word("Fix login page layout", 12),
+            "Fix login…"
+        );
+        assert_eq!(truncate_text_at_word("Fix login page", 10), "Fix login…");
+        assert_eq!(truncate_text_at_word("Supercalifragilistic", 6), "Super…");
+    }
+
+    #[test]
+    fn parse_sensitive_log_hints_trims_and_lowercases() {
+        assert_eq!(
+            parse_sensitive_log_hints(" API_Key, ,x-session "),
+            vec!["api_key", "x-session"]
+        );
+        assert!(parse_sensitive_log_hints("").is_empty());
+    }
+
+    #[test]
+    fn format_elapsed_precise_switches_granularity_by_magnitude() {
+        assert_eq!(format_elapsed_precise(0), "0s");
+        assert_eq!(format_elapsed_precise(59), "59s");
+        assert_eq!(format_elapsed_precise(65), "1m 05s");
+        assert_eq!(format_elapsed_precise(3599), "59m 59s");
+        assert_eq!(format_elapsed_precise(3660), "1h 01m");
+    }
+
+    #[test]
+    fn elapsed_to_duration_input_keeps_exact_seconds() {
+        assert_eq!(elapsed_to_duration_input(0), None);
+        assert_eq!(elapsed_to_duration_input(40).as_deref(), Some("40s"));
+        assert_eq!(elapsed_to_duration_input(3725).as_deref(), Some("1h 2m 5s"));
+        assert_eq!(elapsed_to_duration_input(3600).as_deref(), Some("1h"));
+        let input = elapsed_to_duration_input(90).unwrap();
+        assert_eq!(parse_duration_to_iso(&input).as_deref(), Ok("PT1M30S"));
+    }
+
+    #[test]
+    fn parse_duration_to_iso_accepts_seconds_tokens() {
+        assert_eq!(parse_duration_to_iso("30s").as_deref(), Ok("PT30S"));
+        assert_eq!(parse_duration_to_iso("1h30s").as_deref(), Ok("PT1H30S"));
+        assert_eq!(parse_duration_to_iso("2m15s").as_deref(), Ok("PT2M15S"));
+    }
+
+    #[test]
+    fn parse_duration_to_iso_handles_bare_numbers_and_zero() {
+        assert!(parse_duration_to_iso("  ").is_err());
+        assert_eq!(parse_duration_to_iso("45").as_deref(), Ok("PT45M"));
+        assert_eq!(parse_duration_to_iso("1.5").as_deref(), Ok("PT1H30M"));
+        assert_eq!(parse_duration_to_iso("0.25").as_deref(), Ok("PT15M"));
+        assert_eq!(parse_duration_to_iso("1h 15m").as_deref(), Ok("PT1H15M"));
+        assert!(parse_duration_to_iso("0").is_err());
+        assert!(parse_duration_to_iso("0h0m").is_err());
+    }
+
+    #[test]
+    fn parse_tracker_duration_to_seconds_counts_seconds_tokens() {
+        assert_eq!(parse_tracker_duration_to_seconds("45s", 8), Some(45));
+        assert_eq!(parse_tracker_duration_to_seconds("1h 2m 3s", 8), Some(3723));
+    }
+
+    #[test]
+    fn coerce_comment_author_falls_back_to_uid() {
+        let author = |id: Value| NativeCommentAuthor {
+            id: Some(id),
+            display: None,
+            login: None,
+            email: None,
+        };
+        assert_eq!(
+            coerce_comment_author(&Some(author(json!(1120000000012345u64)))),
+            "User #1120000000012345"
+        );
+        assert_eq!(
+            coerce_comment_author(&Some(author(json!("42")))),
+            "User #42"
+        );
+        assert_eq!(
+            coerce_comment_author(&Some(author(json!("robot")))),
+            "Unknown"
+        );
+        assert_eq!(coerce_comment_author(&None), "Unknown");
+    }
+
+    #[test]
+    fn format_scroll_id_truncates_unless_full() {
+        let id = "0123456789abcdef";
+        assert_eq!(format_scroll_id(Some(id), false), "0123456789ab…");
+        assert_eq!(format_scroll_id(Some(id), true), id);
+        assert_eq!(format_scroll_id(None, true), "root");
+    }
+
+    #[test]
+    fn is_me_token_accepts_call_and_bare_forms() {
+        assert!(is_me_token("me()"));
+        assert!(is_me_token(" ME() "));
+        assert!(is_me_token("me"));
+        assert!(is_me_token(" Me "));
+        assert!(!is_me_token("meg"));
+        assert!(!is_me_token("me(x)"));
+    }
+
+    #[test]
+    fn validate_filter_map_reports_unknown_keys() {
+        let filter = json!({"assignee": "me()", "asignee": "me()", "queue": "YT"});
+        let map = filter.as_object().expect("object");
+        assert_eq!(validate_filter_map(map), vec!["asignee"]);
+    }
+
+    #[test]
+    fn coerce_display_value_array_joins_all_items() {
+        let value = json!(["Backend", null, {"display": "API"}, " "]);
+        assert_eq!(
+            coerce_display_value_array(&value).as_deref(),
+            Some("Backend, API")
+        );
+        assert_eq!(coerce_display_value(&value).as_deref(), Some("Backend"));
+        assert_eq!(coerce_display_value_array(&json!([])), None);
+        assert_eq!(
+            coerce_display_value_array(&json!("Frontend")).as_deref(),
+            Some("Frontend")
+        );
+    }
+
+    #[test]
+    fn parse_duration_value_to_seconds_reads_numbers() {
+        assert_eq!(
+            parse_duration_value_to_seconds(&Value::Number(3600.into()), 8),
+            Some(3600)
+        );
+        assert_eq!(parse_duration_value_to_seconds(&json!(1.5), 8), Some(5400));
+        assert_eq!(parse_duration_value_to_seconds(&json!(-1.5), 8), None);
+    }
+
+    #[test]
+    fn parse_tracker_duration_to_seconds_accepts_iso_8601() {
+        assert_eq!(parse_tracker_duration_to_seconds("PT1H30M", 8), Some(5400));
+        assert_eq!(parse_tracker_duration_to_seconds("P1DT2H", 8), Some(10 * 3600));
+        assert_eq!(parse_tracker_duration_to_seconds("P1W", 8), Some(40 * 3600));
+        assert_eq!(parse_tracker_duration_to_seconds("PT45S", 8), Some(45));
+        assert_eq!(parse_tracker_duration_to_seconds("PT0S", 8), None);
+    }
+}
@@ -0,0 +1,128 @@
+//! Registry of open issue-search scroll contexts, so abandoned ones can be released natively,
+//! plus a holding area for pages prefetched through those contexts.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Thread-safe map of scroll ids to the time they were last handed out to the frontend.
+#[derive(Clone, Default)]
+pub struct ScrollContextRegistry {
+    contexts: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl ScrollContextRegistry {
+    /// Records a scroll id as active, refreshing its timestamp if it is already known.
+    pub fn register(&self, scroll_id: &str) {
+        self.contexts
+            .lock()
+            .unwrap()
+            .insert(scroll_id.to_string(), Instant::now());
+    }
+
+    /// Stops tracking a scroll id, e.g. after the frontend released it explicitly.
+    pub fn forget(&self, scroll_id: &str) {
+        self.contexts.lock().unwrap().remove(scroll_id);
+    }
+
+    /// Removes and returns scroll ids not refreshed within `ttl`.
+    pub fn take_expired(&self, ttl: Duration) -> Vec<String> {
+        self.take_expired_at(Instant::now(), ttl)
+    }
+
+    fn take_expired_at(&self, now: Instant, ttl: Duration) -> Vec<String> {
+        let mut contexts = self.contexts.lock().unwrap();
+        let expired: Vec<String> = contexts
+            .iter()
+            .filter(|(_, registered_at)| now.saturating_duration_since(**registered_at) >= ttl)
+            .map(|(scroll_id, _)| scroll_id.clone())
+            .collect();
+        for scroll_id in &expired {
+            contexts.remove(scroll_id);
+        }
+        expired
+    }
+}
+
+/// Pages fetched ahead of time, keyed by the scroll id that would request them.
+#[derive(Clone)]
+pub struct PrefetchedPages<T> {
+    pages: Arc<Mutex<HashMap<String, (Instant, T)>>>,
+}
+
+impl<T> Default for PrefetchedPages<T> {
+    fn default() -> Self {
+        Self {
+            pages: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl<T> PrefetchedPages<T> {
+    /// Stores a prefetched page, replacing any earlier one for the same scroll id.
+    pub fn insert(&self, scroll_id: &str, page: T) {
+        self.pages
+            .lock()
+            .unwrap()
+            .insert(scroll_id.to_string(), (Instant::now(), page));
+    }
+
+    /// Returns whether a page is waiting for this scroll id.
+    pub fn contains(&self, scroll_id: &str) -> bool {
+        self.pages.lock().unwrap().contains_key(scroll_id)
+    }
+
+    /// Removes the page for a scroll id, returning it only if it was fetched within `ttl`.
+    pub fn take(&self, scroll_id: &str, ttl: Duration) -> Option<T> {
+        let (fetched_at, page) = self.pages.lock().unwrap().remove(scroll_id)?;
+        (fetched_at.elapsed() < ttl).then_some(page)
+    }
+
+    /// Drops pages older than `ttl`; their scroll contexts have expired on the server.
+    pub fn purge_expired(&self, ttl: Duration) {
+        self.pages
+            .lock()
+            .unwrap()
+            .retain(|_, (fetched_at, _)| fetched_at.elapsed() < ttl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrefetchedPages, ScrollContextRegistry};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn take_expired_returns_only_stale_ids_once() {
+        let registry = ScrollContextRegistry::default();
+        registry.register("sid-1");
+        registry.register("sid-2");
+        registry.register("sid-3");
+        registry.forget("sid-3");
+
+        let ttl = Duration::from_secs(60);
+        assert!(registry.take_expired(ttl).is_empty());
+
+        let later = Instant::now() + ttl;
+        let mut expired = registry.take_expired_at(later, ttl);
+        expired.sort();
+        assert_eq!(expired, vec!["sid-1", "sid-2"]);
+        assert!(registry.take_expired_at(later, ttl).is_empty());
+    }
+
+    #[test]
+    fn prefetched_pages_are_taken_once_and_respect_ttl() {
+        let pages = PrefetchedPages::default();
+        pages.insert("sid-1", vec!["YT-101"]);
+        pages.insert("sid-2", vec!["YT-201"]);
+
+        assert!(pages.contains("sid-1"));
+        assert_eq!(
+            pages.take("sid-1", Duration::from_secs(60)),
+            Some(vec!["YT-101"])
+        );
+        assert_eq!(pages.take("sid-1", Duration::from_secs(60)), None);
+        assert_eq!(pages.take("sid-2", Duration::ZERO), None);
+        assert!(!pages.contains("sid-2"));
+    }
+}
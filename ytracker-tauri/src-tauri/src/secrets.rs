@@ -2,13 +2,16 @@
 
 use keyring::{Entry, Error as KeyringError};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tauri::AppHandle;
 use ytracker_api::config::DEFAULT_COOLDOWN_MS;
-use ytracker_api::rate_limiter::RateLimiter;
+use ytracker_api::rate_limiter::{RateLimiter, RateLimiterMetrics};
+use ytracker_api::UserProfile;
 
 const KEYRING_ACCOUNT: &str = "session";
+const KEYRING_CREDENTIALS_ACCOUNT: &str = "client-credentials";
 const KEYRING_FALLBACK_SERVICE: &str = "ru.sovego.ytracker-tauri";
 const LEGACY_KEYRING_SERVICES: [&str; 3] = [
     "ru.sovego.ytracker-tauri",
@@ -17,7 +20,7 @@ const LEGACY_KEYRING_SERVICES: [&str; 3] = [
 ];
 
 /// Represents client credentials required for OAuth authentication, including client ID and secret.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientCredentials {
     pub client_id: String,
     pub client_secret: String,
@@ -31,13 +34,24 @@ pub struct ClientCredentialsInfo {
 }
 
 /// Represents a session token containing access token and organization metadata, which can be persisted in secure storage.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SessionToken {
     pub token: String,
     pub org_id: Option<String>,
     pub org_type: String,
 }
 
+impl fmt::Debug for SessionToken {
+    /// Formats session metadata with the access token redacted.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionToken")
+            .field("token", &"<redacted>")
+            .field("org_id", &self.org_id)
+            .field("org_type", &self.org_type)
+            .finish()
+    }
+}
+
 /// Manages secure storage and retrieval of session tokens and client credentials, with in-memory caching and legacy migration support.
 #[derive(Clone)]
 pub struct SecretsManager {
@@ -50,7 +64,19 @@ struct SecretsInner {
     session_cache: Mutex<Option<SessionToken>>,
     client_id: Option<String>,
     client_secret: Option<String>,
+    runtime_credentials: Arc<Mutex<Option<ClientCredentials>>>,
     rate_limiter: RateLimiter,
+    rate_limiter_metrics: Arc<RateLimiterMetrics>,
+    connectivity: Arc<Mutex<Option<bool>>>,
+    user_profile_cache: Mutex<Option<UserProfile>>,
+    proxy_settings: Mutex<ProxySettings>,
+}
+
+/// Proxy settings applied to every tracker client, mirrored from config.
+#[derive(Clone, Default)]
+pub struct ProxySettings {
+    pub proxy_url: Option<String>,
+    pub no_proxy: Option<String>,
 }
 
 impl SecretsManager {
@@ -63,19 +89,29 @@ impl SecretsManager {
             identifier
         };
 
+        let (rate_limiter, rate_limiter_metrics) =
+            RateLimiter::with_metrics(Duration::from_millis(DEFAULT_COOLDOWN_MS));
         let manager = SecretsManager {
             inner: Arc::new(SecretsInner {
                 keyring_service: service,
                 session_cache: Mutex::new(None),
                 client_id: option_env!("YTRACKER_CLIENT_ID").map(|v| v.to_string()),
                 client_secret: option_env!("YTRACKER_CLIENT_SECRET").map(|v| v.to_string()),
-                rate_limiter: RateLimiter::new(Duration::from_millis(DEFAULT_COOLDOWN_MS)),
+                runtime_credentials: Arc::default(),
+                rate_limiter,
+                rate_limiter_metrics,
+                connectivity: Arc::default(),
+                user_profile_cache: Mutex::new(None),
+                proxy_settings: Mutex::new(ProxySettings::default()),
             }),
         };
 
         let session = manager.load_session_from_store()?;
         *manager.inner.session_cache.lock().unwrap() = session;
 
+        let credentials = manager.load_credentials_from_store()?;
+        *manager.inner.runtime_credentials.lock().unwrap() = credentials;
+
         Ok(manager)
     }
 
@@ -84,8 +120,44 @@ impl SecretsManager {
         self.inner.rate_limiter.clone()
     }
 
+    /// Returns counters shared with the API rate limiter.
+    pub fn get_rate_limiter_metrics(&self) -> Arc<RateLimiterMetrics> {
+        self.inner.rate_limiter_metrics.clone()
+    }
+
+    /// Returns last known API connectivity shared by all tracker clients.
+    pub fn get_connectivity_state(&self) -> Arc<Mutex<Option<bool>>> {
+        self.inner.connectivity.clone()
+    }
+
+    /// Returns the signed-in user's profile if it was already fetched during this session.
+    pub fn get_cached_user_profile(&self) -> Option<UserProfile> {
+        self.inner.user_profile_cache.lock().unwrap().clone()
+    }
+
+    /// Remembers the signed-in user's profile until the session changes.
+    pub fn set_cached_user_profile(&self, profile: UserProfile) {
+        *self.inner.user_profile_cache.lock().unwrap() = Some(profile);
+    }
+
+    /// Returns the proxy settings used when building tracker clients.
+    pub fn get_proxy_settings(&self) -> ProxySettings {
+        self.inner.proxy_settings.lock().unwrap().clone()
+    }
+
+    /// Replaces the proxy settings, e.g. after the config changed.
+    pub fn set_proxy_settings(&self, settings: ProxySettings) {
+        *self.inner.proxy_settings.lock().unwrap() = settings;
+    }
+
     /// Returns safe-to-display metadata about configured client credentials.
     pub fn get_public_info(&self) -> Result<ClientCredentialsInfo, String> {
+        if let Some(credentials) = self.inner.runtime_credentials.lock().unwrap().as_ref() {
+            return Ok(ClientCredentialsInfo {
+                client_id: Some(credentials.client_id.clone()),
+                has_client_secret: true,
+            });
+        }
         Ok(ClientCredentialsInfo {
             client_id: self.inner.client_id.clone(),
             has_client_secret: self.inner.client_secret.is_some(),
@@ -93,7 +165,12 @@ impl SecretsManager {
     }
 
     /// Returns OAuth client credentials if both id and secret are configured.
+    ///
+    /// Credentials saved at runtime take precedence over build-time values.
     pub fn get_credentials(&self) -> Result<Option<ClientCredentials>, String> {
+        if let Some(credentials) = self.inner.runtime_credentials.lock().unwrap().clone() {
+            return Ok(Some(credentials));
+        }
         match (&self.inner.client_id, &self.inner.client_secret) {
             (Some(id), Some(secret)) => Ok(Some(ClientCredentials {
                 client_id: id.clone(),
@@ -103,6 +180,31 @@ impl SecretsManager {
         }
     }
 
+    /// Replaces OAuth client credentials at runtime and persists them into secure storage.
+    pub fn rotate_credentials(&self, client_id: &str, client_secret: &str) -> Result<(), String> {
+        let client_id = client_id.trim();
+        let client_secret = client_secret.trim();
+        if client_id.is_empty() {
+            return Err("Client ID must not be empty".into());
+        }
+        if client_secret.is_empty() {
+            return Err("Client secret must not be empty".into());
+        }
+
+        let credentials = ClientCredentials {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+        };
+        let payload = serde_json::to_string(&credentials)
+            .map_err(|err| format!("Failed to serialize client credentials: {err}"))?;
+        self.credentials_entry()?
+            .set_password(&payload)
+            .map_err(|err| format!("Failed to store client credentials in keyring: {err}"))?;
+        *self.inner.runtime_credentials.lock().unwrap() = Some(credentials);
+
+        Ok(())
+    }
+
     /// Persists OAuth access token and organization metadata into secure storage.
     pub fn save_session(
         &self,
@@ -128,6 +230,7 @@ impl SecretsManager {
 
         self.persist_session(Some(&session))?;
         *self.inner.session_cache.lock().unwrap() = Some(session);
+        *self.inner.user_profile_cache.lock().unwrap() = None;
 
         Ok(())
     }
@@ -146,10 +249,11 @@ impl SecretsManager {
         Ok(session)
     }
 
-    /// Clears persisted session and in-memory cache.
+    /// Clears persisted session and in-memory caches, including the user profile.
     pub fn clear_session(&self) -> Result<(), String> {
         self.persist_session(None)?;
         *self.inner.session_cache.lock().unwrap() = None;
+        *self.inner.user_profile_cache.lock().unwrap() = None;
         Ok(())
     }
 
@@ -204,6 +308,27 @@ impl SecretsManager {
         }
     }
 
+    /// Reads runtime OAuth client credentials saved by [`Self::rotate_credentials`].
+    fn load_credentials_from_store(&self) -> Result<Option<ClientCredentials>, String> {
+        match self.credentials_entry()?.get_password() {
+            Ok(secret) => serde_json::from_str(&secret)
+                .map(Some)
+                .map_err(|err| format!("Failed to decode stored client credentials: {err}")),
+            Err(KeyringError::NoEntry) => Ok(None),
+            Err(err) => Err(format!(
+                "Failed to read client credentials from keyring: {err}"
+            )),
+        }
+    }
+
+    /// Returns keyring entry holding runtime OAuth client credentials.
+    fn credentials_entry(&self) -> Result<Entry, String> {
+        let service = &self.inner.keyring_service;
+        Entry::new(service, KEYRING_CREDENTIALS_ACCOUNT).map_err(|err| {
+            format!("Failed to open client credentials keyring entry for '{service}': {err}")
+        })
+    }
+
     /// Returns keyring entry for the active service identifier.
     fn session_entry(&self) -> Result<Entry, String> {
         self.session_entry_for_service(&self.inner.keyring_service)
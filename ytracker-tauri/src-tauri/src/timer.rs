@@ -1,8 +1,12 @@
 //! Timer state machine used for local issue time tracking.
 
 use serde::Serialize;
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
+const SESSION_LOG_CAPACITY: usize = 20;
+const MAX_START_ADJUST_SECS: u64 = 24 * 60 * 60;
+
 /// Represents the current state of the timer, including whether it's active, which issue is being tracked, when it started and how much time has elapsed.
 #[derive(Clone, Serialize, Debug)]
 pub struct TimerState {
@@ -11,12 +15,28 @@ pub struct TimerState {
     pub issue_summary: Option<String>,
     pub start_time: Option<u64>,
     pub elapsed: u64,
+    /// Cached total logged today, filled in by the app from the issue store.
+    pub total_today_seconds: u64,
+    /// Original estimate of the tracked issue, when the caller provided one.
+    pub estimated_seconds: Option<u64>,
+}
+
+/// Represents a finished timer run kept in the session log.
+#[derive(Clone, Serialize, Debug)]
+pub struct CompletedSession {
+    pub issue_key: String,
+    pub issue_summary: Option<String>,
+    pub started_at: u64,
+    pub elapsed: u64,
 }
 
 /// Thread-safe timer runtime storing active issue and elapsed tracking data.
 pub struct Timer {
     state: Arc<Mutex<TimerState>>,
     last_notification_at: Arc<Mutex<Option<u64>>>,
+    last_break_reminder_at: Arc<Mutex<Option<u64>>>,
+    overflow_notified: Arc<Mutex<bool>>,
+    sessions: Arc<Mutex<VecDeque<CompletedSession>>>,
 }
 
 impl Timer {
@@ -29,8 +49,13 @@ impl Timer {
                 issue_summary: None,
                 start_time: None,
                 elapsed: 0,
+                total_today_seconds: 0,
+                estimated_seconds: None,
             })),
             last_notification_at: Arc::new(Mutex::new(None)),
+            last_break_reminder_at: Arc::new(Mutex::new(None)),
+            overflow_notified: Arc::new(Mutex::new(false)),
+            sessions: Arc::new(Mutex::new(VecDeque::with_capacity(SESSION_LOG_CAPACITY))),
         }
     }
 
@@ -44,6 +69,16 @@ impl Timer {
 
     /// Starts tracking time for an issue and resets elapsed counter.
     pub fn start(&self, issue_key: String, issue_summary: Option<String>) {
+        self.start_with_estimate(issue_key, issue_summary, None);
+    }
+
+    /// Starts tracking time for an issue, remembering its estimate for overflow warnings.
+    pub fn start_with_estimate(
+        &self,
+        issue_key: String,
+        issue_summary: Option<String>,
+        estimated_seconds: Option<u64>,
+    ) {
         let now = Self::now_secs();
         {
             let mut state = self.state.lock().unwrap();
@@ -52,9 +87,15 @@ impl Timer {
             state.issue_summary = issue_summary;
             state.start_time = Some(now);
             state.elapsed = 0;
+            state.estimated_seconds = estimated_seconds;
         }
+        *self.overflow_notified.lock().unwrap() = false;
         let mut last_notification = self.last_notification_at.lock().unwrap();
         *last_notification = Some(now);
+        drop(last_notification);
+
+        let mut last_break_reminder = self.last_break_reminder_at.lock().unwrap();
+        *last_break_reminder = Some(now);
     }
 
     /// Stops timer and returns elapsed seconds with previously active issue key.
@@ -68,21 +109,66 @@ impl Timer {
         let start = state.start_time.unwrap_or(now);
         let elapsed = now - start;
         let key = state.issue_key.clone();
+        let summary = state.issue_summary.clone();
 
         state.active = false;
         state.issue_key = None;
         state.issue_summary = None;
         state.start_time = None;
         state.elapsed = 0;
+        state.estimated_seconds = None;
 
         drop(state);
 
         let mut last_notification = self.last_notification_at.lock().unwrap();
         *last_notification = None;
+        drop(last_notification);
+
+        let mut last_break_reminder = self.last_break_reminder_at.lock().unwrap();
+        *last_break_reminder = None;
+        drop(last_break_reminder);
+
+        if let Some(issue_key) = key.clone() {
+            let mut sessions = self.sessions.lock().unwrap();
+            sessions.push_front(CompletedSession {
+                issue_key,
+                issue_summary: summary,
+                started_at: start,
+                elapsed,
+            });
+            sessions.truncate(SESSION_LOG_CAPACITY);
+        }
 
         (elapsed, key)
     }
 
+    /// Moves the active start time `offset_secs` earlier (negative values move it later).
+    ///
+    /// The result is clamped to lie between 24 hours ago and now.
+    pub fn adjust_start_time(&self, offset_secs: i64) -> Result<(), &'static str> {
+        let mut state = self.state.lock().unwrap();
+        if !state.active {
+            return Err("Timer is not running");
+        }
+
+        let now = Self::now_secs();
+        let start = state.start_time.unwrap_or(now) as i64;
+        let earliest = now.saturating_sub(MAX_START_ADJUST_SECS) as i64;
+        let adjusted = start.saturating_sub(offset_secs).clamp(earliest, now as i64);
+        state.start_time = Some(adjusted as u64);
+        Ok(())
+    }
+
+    /// Returns recently completed sessions, most recent first.
+    pub fn session_log(&self) -> Vec<CompletedSession> {
+        self.sessions.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Forgets all completed sessions.
+    pub fn clear_session_log(&self) {
+        self.sessions.lock().unwrap().clear();
+    }
+
     /// Returns a snapshot with elapsed recomputed when timer is active.
     pub fn get_state(&self) -> TimerState {
         let state = self.state.lock().unwrap();
@@ -121,6 +207,48 @@ impl Timer {
         *last_notification = Some(now);
         Some(snapshot)
     }
+
+    /// Returns timer snapshot once per session when elapsed time first exceeds the estimate.
+    pub fn check_estimate_overflow(&self) -> Option<TimerState> {
+        let snapshot = self.get_state();
+        if !snapshot.active || snapshot.elapsed <= snapshot.estimated_seconds.unwrap_or(u64::MAX) {
+            return None;
+        }
+
+        let mut overflow_notified = self.overflow_notified.lock().unwrap();
+        if *overflow_notified {
+            return None;
+        }
+        *overflow_notified = true;
+        Some(snapshot)
+    }
+
+    /// Returns timer snapshot when the timer has run for `interval_secs` since start or the last break reminder.
+    pub fn check_break_reminder_due(&self, interval_secs: u64) -> Option<TimerState> {
+        if interval_secs == 0 {
+            return None;
+        }
+
+        let now = Self::now_secs();
+        let state = self.state.lock().unwrap();
+        if !state.active {
+            return None;
+        }
+
+        let start = state.start_time.unwrap_or(now);
+        let mut snapshot = state.clone();
+        snapshot.elapsed = now.saturating_sub(start);
+        drop(state);
+
+        let mut last_break_reminder = self.last_break_reminder_at.lock().unwrap();
+        let last_time = last_break_reminder.unwrap_or(start);
+        if now.saturating_sub(last_time) < interval_secs {
+            return None;
+        }
+
+        *last_break_reminder = Some(now);
+        Some(snapshot)
+    }
 }
 
 #[cfg(test)]
@@ -168,6 +296,53 @@ mod tests {
         assert_eq!(result, (0, None));
     }
 
+    #[test]
+    fn adjust_start_time_moves_start_within_allowed_window() {
+        let timer = Timer::new();
+        assert!(timer.adjust_start_time(60).is_err());
+
+        timer.start("YT-120".to_string(), None);
+        let started = timer.get_state().start_time.expect("start time");
+
+        timer.adjust_start_time(30 * 60).expect("adjust back");
+        let adjusted = timer.get_state().start_time.expect("start time");
+        assert_eq!(started - adjusted, 30 * 60);
+
+        timer.adjust_start_time(-3600).expect("adjust forward");
+        assert!(timer.get_state().start_time.expect("start time") <= Timer::now_secs());
+
+        timer.adjust_start_time(i64::MAX).expect("adjust far back");
+        let earliest = timer.get_state().start_time.expect("start time");
+        assert!(Timer::now_secs() - earliest <= MAX_START_ADJUST_SECS);
+    }
+
+    #[test]
+    fn stop_records_completed_sessions_most_recent_first() {
+        let timer = Timer::new();
+        timer.stop();
+        assert!(timer.session_log().is_empty());
+
+        timer.start("YT-110".to_string(), Some("First".to_string()));
+        timer.stop();
+        timer.start("YT-111".to_string(), None);
+        timer.stop();
+
+        let log = timer.session_log();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].issue_key, "YT-111");
+        assert_eq!(log[1].issue_key, "YT-110");
+        assert_eq!(log[1].issue_summary.as_deref(), Some("First"));
+
+        for index in 0..SESSION_LOG_CAPACITY {
+            timer.start(format!("YT-{index}"), None);
+            timer.stop();
+        }
+        assert_eq!(timer.session_log().len(), SESSION_LOG_CAPACITY);
+
+        timer.clear_session_log();
+        assert!(timer.session_log().is_empty());
+    }
+
     #[test]
     fn check_notification_due_respects_interval_and_active_state() {
         let timer = Timer::new();
@@ -188,10 +363,57 @@ mod tests {
         assert!(immediate_second.is_none());
     }
 
+    #[test]
+    fn check_estimate_overflow_fires_once_per_session() {
+        let timer = Timer::new();
+        timer.start("YT-130".to_string(), None);
+        assert!(timer.check_estimate_overflow().is_none());
+
+        timer.start_with_estimate("YT-131".to_string(), None, Some(60));
+        assert!(timer.check_estimate_overflow().is_none());
+
+        {
+            let mut state = timer.state.lock().unwrap();
+            state.start_time = Some(Timer::now_secs().saturating_sub(120));
+        }
+        let overflow = timer.check_estimate_overflow().expect("estimate exceeded");
+        assert_eq!(overflow.issue_key.as_deref(), Some("YT-131"));
+        assert!(timer.check_estimate_overflow().is_none());
+
+        timer.start_with_estimate("YT-132".to_string(), None, Some(0));
+        {
+            let mut state = timer.state.lock().unwrap();
+            state.start_time = Some(Timer::now_secs().saturating_sub(5));
+        }
+        assert!(timer.check_estimate_overflow().is_some());
+    }
+
     #[test]
     fn check_notification_due_returns_none_for_zero_interval() {
         let timer = Timer::new();
         timer.start("YT-104".to_string(), None);
         assert!(timer.check_notification_due(0).is_none());
     }
+
+    #[test]
+    fn check_break_reminder_due_tracks_separately_from_notifications() {
+        let timer = Timer::new();
+        assert!(timer.check_break_reminder_due(60).is_none());
+
+        timer.start("YT-105".to_string(), None);
+        assert!(timer.check_break_reminder_due(0).is_none());
+        assert!(timer.check_break_reminder_due(3600).is_none());
+
+        {
+            let mut last = timer.last_break_reminder_at.lock().unwrap();
+            *last = Some(0);
+        }
+
+        assert!(timer.check_break_reminder_due(60).is_some());
+        assert!(timer.check_break_reminder_due(60).is_none());
+        assert!(timer.last_notification_at.lock().unwrap().is_some());
+
+        timer.stop();
+        assert!(timer.last_break_reminder_at.lock().unwrap().is_none());
+    }
 }
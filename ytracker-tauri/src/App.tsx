@@ -50,6 +50,7 @@ const isAuthRelatedError = (message: string) => {
     normalized.includes("forbidden") ||
     normalized.includes("access denied") ||
     normalized.includes("not authenticated") ||
+    normalized.includes("session-expired") ||
     normalized.includes("sign in again") ||
     normalized.includes("failed to load stored token")
   );
@@ -372,6 +373,19 @@ function App() {
     }
   }, [error]);
 
+  // Native side emits this when Tracker rejects the stored token (e.g. background refresh).
+  useEffect(() => {
+    const unlisten = listen("session-expired", () => {
+      setIsAuthenticated(false);
+    });
+
+    return () => {
+      unlisten.then((dispose) => dispose()).catch((err) => {
+        console.warn(`Failed to dispose session-expired listener (${getErrorSummary(err)})`);
+      });
+    };
+  }, []);
+
   /** Prevents native form submit; filtering is applied explicitly via controls. */
   const handleLocalSearchSubmit = (e: FormEvent) => {
     e.preventDefault();
@@ -449,10 +463,10 @@ function App() {
     dismissWorkLogDialog();
   };
 
-  /** Stops active timer and opens worklog dialog for captured duration. */
+  /** Stops active timer and opens worklog dialog for captured duration unless it was auto-logged. */
   const handleStopTimer = async () => {
-    const [elapsed, key] = await invokeStopTimer();
-    if (key && elapsed > 0) {
+    const [elapsed, key, autoLogged] = await invokeStopTimer();
+    if (key && elapsed > 0 && !autoLogged) {
       openWorkLogDialog(key, elapsed);
     }
   };
@@ -492,8 +506,8 @@ function App() {
         }
 
         if (decision.includes("save") || decision === "yes" || decision === "ok") {
-          const [elapsed, previousKey] = await invokeStopTimer();
-          if (previousKey && elapsed > 0) {
+          const [elapsed, previousKey, autoLogged] = await invokeStopTimer();
+          if (previousKey && elapsed > 0 && !autoLogged) {
             openWorkLogDialog(previousKey, elapsed, { key: issueKey, summary: issueSummary });
           } else {
             await invokeStartTimer(issueKey, issueSummary);
@@ -23,8 +23,13 @@ export interface Issue {
     issue_type?: SimpleEntity | null;
     assignee?: SimpleEntity | null;
     tags?: string[];
+    components?: string[];
     followers?: SimpleEntity[];
     tracked_seconds?: number | null;
+    parent_key?: string | null;
+    parent_summary?: string | null;
+    deadline?: string | null;
+    votes?: number | null;
 }
 
 type IssuePageResponse = {
@@ -66,16 +71,43 @@ export interface TimerState {
     issue_summary?: string | null;
     start_time: number | null;
     elapsed: number;
+    total_today_seconds?: number;
+    estimated_seconds?: number | null;
+}
+
+/**
+ * Finished timer run from the in-memory session log (last 20 sessions).
+ */
+export interface CompletedSession {
+    issue_key: string;
+    issue_summary?: string | null;
+    started_at: number;
+    elapsed: number;
 }
 
 /**
  * Persisted desktop configuration synchronized with native config manager.
  */
 export interface Config {
+    schema_version?: number;
     timer_notification_interval: number;
     workday_hours: number;
     workday_start_time: string;
     workday_end_time: string;
+    auto_log_on_timer_stop?: boolean;
+    auto_log_min_seconds?: number;
+    custom_motivational_phrases?: string[];
+    tray_group_by_queue?: boolean;
+    workday_start_reminder?: boolean;
+    break_reminder_enabled?: boolean;
+    break_reminder_minutes?: number;
+    proxy_url?: string | null;
+    /** Comma-separated hosts that bypass `proxy_url`. */
+    no_proxy?: string | null;
+    workday_timezone?: string | null;
+    issue_display_fields?: string[];
+    /** Largest attachment, in bytes, fetched for inline preview; larger files must be downloaded. */
+    preview_max_bytes?: number;
 }
 
 /**
@@ -86,6 +118,40 @@ export interface ClientCredentialsInfo {
     has_client_secret: boolean;
 }
 
+/**
+ * PKCE verifier/challenge pair for OAuth without a client secret.
+ */
+export interface PkcePair {
+    code_verifier: string;
+    code_challenge: string;
+}
+
+/**
+ * Accepted bulk change operation; Tracker applies the patch asynchronously.
+ */
+export interface BulkChangeResult {
+    operation_id: string;
+}
+
+/**
+ * API rate limiter counters exposed for diagnostics.
+ */
+export interface RateLimiterMetrics {
+    total_hits: number;
+    throttled_hits: number;
+    last_throttle_ms?: number | null;
+}
+
+/**
+ * App build and Tracker API version details for the about page and bug reports.
+ */
+export interface VersionInfo {
+    app_version: string;
+    api_version: string;
+    build_date?: string | null;
+    platform: string;
+}
+
 /**
  * Simplified issue comment model used in issue details view.
  */
@@ -93,9 +159,31 @@ export interface Comment {
     id: string;
     text: string;
     author: string;
+    author_login?: string | null;
     created_at: string;
 }
 
+/**
+ * Issue linked to another issue with relation type and direction.
+ */
+export interface LinkedIssue {
+    relation_type: string;
+    direction: "inward" | "outward" | string;
+    issue: Issue;
+}
+
+/**
+ * Single field change from an issue changelog event.
+ */
+export interface ChangelogEntry {
+    id: string;
+    timestamp: string;
+    author: string;
+    field: string;
+    from_value?: string | null;
+    to_value?: string | null;
+}
+
 /**
  * Simplified issue attachment metadata for list/download interactions.
  */
@@ -115,6 +203,25 @@ export interface WorklogEntry {
     duration_seconds: number;
     comment: string;
     author: string;
+    issue_key?: string | null;
+}
+
+/**
+ * Per-issue logged time for a single day.
+ */
+export interface DailySummaryEntry {
+    issue_key: string;
+    issue_summary: string;
+    seconds: number;
+}
+
+/**
+ * Logged time for one local day with the underlying worklog entries.
+ */
+export interface DaySummary {
+    date: string;
+    total_seconds: number;
+    entries: WorklogEntry[];
 }
 
 /**
@@ -131,6 +238,15 @@ export interface ChecklistItem {
     item_type?: string | null;
 }
 
+/**
+ * Checklist completion summary for issue cards.
+ */
+export interface ChecklistProgress {
+    total: number;
+    checked: number;
+    percent: number;
+}
+
 /**
  * Payload used when creating a new checklist item.
  */
@@ -159,9 +275,17 @@ export interface ChecklistItemUpdatePayload {
 export interface Transition {
     id: string;
     name: string;
+    description?: string | null;
     to_status: { key: string; display: string } | null;
 }
 
+/**
+ * Workflow transition with the fields its transition screen asks for.
+ */
+export interface TransitionWithFields extends Transition {
+    required_fields: FieldDefinition[];
+}
+
 /**
  * Binary attachment preview payload with base64-encoded bytes.
  */
@@ -174,6 +298,7 @@ export interface AttachmentPreview {
  * Current user profile surfaced in settings/account UI.
  */
 export interface UserProfile {
+    uid?: string | null;
     display?: string | null;
     login?: string | null;
     email?: string | null;
@@ -188,6 +313,29 @@ export interface SimpleEntity {
     display: string;
 }
 
+/**
+ * Queue details shown in the queue detail view.
+ */
+export interface Queue {
+    id?: string | null;
+    key: string;
+    name: string;
+    description?: string | null;
+    lead?: UserProfile | null;
+    default_type?: SimpleEntity | null;
+}
+
+/**
+ * Queue field definition used to render dynamic issue forms.
+ */
+export interface FieldDefinition {
+    id: string;
+    name: string;
+    type_id?: string | null;
+    required: boolean;
+    allowed_values: SimpleEntity[];
+}
+
 /**
  * Updater available-event payload (mirrors `updater://available`).
  */
@@ -222,10 +370,23 @@ const CONFIG_UPDATED_EVENT = "ytracker:config-updated";
 
 /** Normalizes config payload shape from backend before storing in cache/state. */
 const normalizeConfig = (data: Config): Config => ({
+    schema_version: data.schema_version,
     timer_notification_interval: data.timer_notification_interval,
     workday_hours: data.workday_hours,
     workday_start_time: data.workday_start_time,
     workday_end_time: data.workday_end_time,
+    auto_log_on_timer_stop: data.auto_log_on_timer_stop,
+    auto_log_min_seconds: data.auto_log_min_seconds,
+    custom_motivational_phrases: data.custom_motivational_phrases,
+    tray_group_by_queue: data.tray_group_by_queue,
+    workday_start_reminder: data.workday_start_reminder,
+    break_reminder_enabled: data.break_reminder_enabled,
+    break_reminder_minutes: data.break_reminder_minutes,
+    proxy_url: data.proxy_url ?? null,
+    no_proxy: data.no_proxy ?? null,
+    workday_timezone: data.workday_timezone ?? null,
+    issue_display_fields: data.issue_display_fields,
+    preview_max_bytes: data.preview_max_bytes,
 });
 
 /** Loads config with in-flight promise coalescing and optional cache bypass. */
@@ -574,6 +735,21 @@ export function useIssueDetails() {
         return invoke<Issue>("get_issue", { issueKey });
     };
 
+    /** Returns the parent issue of a sub-task, or null when there is none. */
+    const getParentIssue = async (issueKey: string) => {
+        return invoke<Issue | null>("get_parent_issue", { issueKey });
+    };
+
+    /** Returns issues linked to the given issue with relation metadata. */
+    const getIssueLinkedIssues = async (issueKey: string) => {
+        return invoke<LinkedIssue[]>("get_issue_linked_issues", { issueKey });
+    };
+
+    /** Opens the issue page in the system browser. */
+    const openIssueInBrowser = async (issueKey: string) => {
+        await invoke("open_issue_in_browser", { issueKey });
+    };
+
     const getComments = async (issueKey: string, options?: { forceRefresh?: boolean }) => {
         return fetchWithCache(
             detailCache.comments,
@@ -583,16 +759,83 @@ export function useIssueDetails() {
         );
     };
 
+    /** Returns a single issue comment by id, bypassing the comments cache. */
+    const getComment = async (issueKey: string, commentId: string) => {
+        return invoke<Comment>("get_comment", { issueKey, commentId });
+    };
+
+    /** Returns issue change history as a flat list of field changes. */
+    const getIssueChangelog = async (issueKey: string) => {
+        return invoke<ChangelogEntry[]>("get_issue_changelog", { issueKey });
+    };
+
     const addComment = async (issueKey: string, text: string) => {
         const result = await invoke("add_comment", { issueKey, text });
         invalidateCache(issueKey, "comments");
         return result;
     };
 
+    /** Adds an emoji reaction to a comment; the backend rejects non-emoji values. */
+    const addCommentReaction = async (issueKey: string, commentId: string, emoji: string) => {
+        await invoke("add_comment_reaction", { issueKey, commentId, emoji });
+        invalidateCache(issueKey, "comments");
+    };
+
+    /** Removes an emoji reaction from a comment. */
+    const removeCommentReaction = async (issueKey: string, commentId: string, emoji: string) => {
+        await invoke("remove_comment_reaction", { issueKey, commentId, emoji });
+        invalidateCache(issueKey, "comments");
+    };
+
     const updateIssue = async (issueKey: string, summary?: string, description?: string) => {
         return invoke("update_issue", { issueKey, summary, description });
     };
 
+    /** Returns raw issue fields outside the summary model (custom fields). */
+    const getIssueCustomFields = async (issueKey: string) => {
+        return invoke<Record<string, unknown>>("get_issue_custom_fields", { issueKey });
+    };
+
+    /** Sets a single issue field by key, typically a queue-specific custom field. */
+    const updateIssueCustomField = async (issueKey: string, fieldKey: string, value: unknown) => {
+        await invoke("update_issue_custom_field", { issueKey, fieldKey, value });
+    };
+
+    const getIssueTags = async (issueKey: string) => {
+        return invoke<string[]>("get_issue_tags", { issueKey });
+    };
+
+    /** Replaces the whole tag list; resolves with the trimmed, deduplicated tags that were saved. */
+    const setIssueTags = async (issueKey: string, tags: string[]) => {
+        return invoke<string[]>("set_issue_tags", { issueKey, tags });
+    };
+
+    /** Adds one tag while keeping the others; resolves with the resulting tag list. */
+    const addIssueTag = async (issueKey: string, tag: string) => {
+        return invoke<string[]>("add_issue_tag", { issueKey, tag });
+    };
+
+    const getIssueVotes = async (issueKey: string) => {
+        return invoke<number>("get_issue_votes", { issueKey });
+    };
+
+    const voteForIssue = async (issueKey: string) => {
+        await invoke("vote_for_issue", { issueKey });
+    };
+
+    const unvoteIssue = async (issueKey: string) => {
+        await invoke("unvote_issue", { issueKey });
+    };
+
+    /** Subscribes the current user to notifications; unlike followers, this only affects the caller. */
+    const subscribeToIssue = async (issueKey: string) => {
+        await invoke("subscribe_to_issue", { issueKey });
+    };
+
+    const unsubscribeFromIssue = async (issueKey: string) => {
+        await invoke("unsubscribe_from_issue", { issueKey });
+    };
+
     const getAttachments = async (issueKey: string, options?: { forceRefresh?: boolean }) => {
         return fetchWithCache(
             detailCache.attachments,
@@ -619,6 +862,11 @@ export function useIssueDetails() {
         );
     };
 
+    /** Returns transitions with screen fields; not cached since screens are fetched right before a transition. */
+    const getTransitionsWithFields = async (issueKey: string) => {
+        return invoke<TransitionWithFields[]>("get_transitions_with_fields", { issueKey });
+    };
+
     const getIssueWorklogs = async (issueKey: string, options?: { forceRefresh?: boolean }) => {
         return fetchWithCache(
             detailCache.worklogs,
@@ -632,12 +880,53 @@ export function useIssueDetails() {
         return invoke<number>("get_today_logged_seconds_for_issues", { issueKeys });
     }, []);
 
+    /** Returns logged time grouped per issue for a day (`YYYY-MM-DD`, defaults to today). */
+    const getDailyWorklogBreakdown = useCallback(async (date?: string | null) => {
+        return invoke<DailySummaryEntry[]>("get_daily_worklog_breakdown", { date: date ?? null });
+    }, []);
+
+    /** Returns 7 per-day summaries starting at `weekStartDate` (`YYYY-MM-DD`, defaults to this Monday). */
+    const getWeeklySummary = useCallback(async (weekStartDate?: string | null) => {
+        return invoke<DaySummary[]>("get_weekly_summary", { weekStartDate: weekStartDate ?? null });
+    }, []);
+
+    /** Returns an issue's worklogs within an inclusive `YYYY-MM-DD` date range; preferred for reports. */
+    const getIssueWorklogsInRange = async (issueKey: string, fromDate: string, toDate: string) => {
+        return invoke<WorklogEntry[]>("get_issue_worklogs_in_range", { issueKey, fromDate, toDate });
+    };
+
+    /** Exports worklogs for issues in an inclusive date range; returns written row count. */
+    const exportWorklogsCsv = async (issueKeys: string[], dateFrom: string, dateTo: string, destPath: string) => {
+        return invoke<number>("export_worklogs_csv", { issueKeys, dateFrom, dateTo, destPath });
+    };
+
+    /** Exports every issue matching the query or filter to a JSON file; returns the issue count. */
+    const exportAllIssuesJson = async (destPath: string, query?: string, filter?: Record<string, unknown>) => {
+        return invoke<number>("export_all_issues_json", { query, filter, destPath });
+    };
+
     const executeTransition = async (issueKey: string, transitionId: string, comment?: string, resolution?: string) => {
         const result = await invoke("execute_transition", { issueKey, transitionId, comment, resolution });
         invalidateCache(issueKey, "transitions");
         return result;
     };
 
+    /** Executes a transition with transition screen fields; `comment`/`resolution` go into `fields` too. */
+    const executeTransitionWithFields = async (
+        issueKey: string,
+        transitionId: string,
+        fields: Record<string, unknown>
+    ) => {
+        await invoke("execute_transition_with_fields", { issueKey, transitionId, fields });
+        invalidateCache(issueKey, "transitions");
+    };
+
+    /** Moves an issue to the first available done status. */
+    const markIssueDone = async (issueKey: string) => {
+        await invoke("mark_issue_done", { issueKey });
+        invalidateCache(issueKey, "transitions");
+    };
+
     const previewAttachment = async (issueKey: string, attachmentId: string) => {
         return invoke<AttachmentPreview>("preview_attachment", { issueKey, attachmentId });
     };
@@ -687,6 +976,10 @@ export function useIssueDetails() {
         );
     };
 
+    const getChecklistProgress = async (issueKey: string) => {
+        return invoke<ChecklistProgress>("get_checklist_progress", { issueKey });
+    };
+
     const addChecklistItem = async (issueKey: string, item: ChecklistItemCreatePayload) => {
         await invoke("add_checklist_item", { issueKey, item });
         invalidateCache(issueKey, "checklist");
@@ -697,6 +990,27 @@ export function useIssueDetails() {
         invalidateCache(issueKey, "checklist");
     };
 
+    const bulkCheckChecklistItems = async (issueKey: string, itemIds: string[]) => {
+        try {
+            await invoke("bulk_check_checklist_items", { issueKey, itemIds });
+        } finally {
+            invalidateCache(issueKey, "checklist");
+        }
+    };
+
+    const bulkUncheckChecklistItems = async (issueKey: string, itemIds: string[]) => {
+        try {
+            await invoke("bulk_uncheck_checklist_items", { issueKey, itemIds });
+        } finally {
+            invalidateCache(issueKey, "checklist");
+        }
+    };
+
+    const duplicateChecklistItem = async (issueKey: string, itemId: string) => {
+        await invoke("duplicate_checklist_item", { issueKey, itemId });
+        invalidateCache(issueKey, "checklist");
+    };
+
     const deleteChecklist = async (issueKey: string) => {
         await invoke("delete_checklist", { issueKey });
         invalidateCache(issueKey, "checklist");
@@ -719,6 +1033,26 @@ export function useIssueDetails() {
         return invoke<Attachment>("upload_temp_attachment", { filePath });
     };
 
+    /** Returns field definitions available in a queue. */
+    const getQueueFields = async (queueKey: string): Promise<FieldDefinition[]> => {
+        return invoke<FieldDefinition[]>("get_queue_fields", { queueKey });
+    };
+
+    /** Returns queue details: description, lead and default issue type. */
+    const getQueue = async (queueKey: string): Promise<Queue> => {
+        return invoke<Queue>("get_queue", { queueKey });
+    };
+
+    /** Returns components defined in a queue. */
+    const getQueueComponents = async (queueKey: string): Promise<SimpleEntity[]> => {
+        return invoke<SimpleEntity[]>("get_queue_components", { queueKey });
+    };
+
+    /** Returns issue types available in a queue, for the issue creation form. */
+    const getQueueIssueTypes = async (queueKey: string): Promise<SimpleEntity[]> => {
+        return invoke<SimpleEntity[]>("get_queue_issue_types", { queueKey });
+    };
+
     /** Creates a new issue in the specified queue. Returns the created issue. */
     const createIssue = async (params: {
         queue: string;
@@ -742,6 +1076,27 @@ export function useIssueDetails() {
         });
     };
 
+    /**
+     * Creates a copy of an issue in the same queue. Only summary, description, type,
+     * priority and queue are copied; watchers, worklogs and attachments are not.
+     */
+    const cloneIssue = async (sourceKey: string, newSummary?: string | null): Promise<Issue> => {
+        return invoke<Issue>("clone_issue", { sourceKey, newSummary: newSummary ?? null });
+    };
+
+    /** Applies one field patch to several issues at once and drops their cached details. */
+    const bulkUpdateIssues = async (keys: string[], patch: Record<string, unknown>) => {
+        const result = await invoke<BulkChangeResult>("bulk_update_issues", { keys, patch });
+        keys.forEach((key) => invalidateCache(key, "all"));
+        return result;
+    };
+
+    /** Moves an issue to another queue. The issue gets a new key, so callers should reload lists. */
+    const moveIssueToQueue = async (issueKey: string, targetQueueKey: string) => {
+        await invoke("move_issue_to_queue", { issueKey, targetQueueKey });
+        invalidateCache(issueKey, "all");
+    };
+
     /** Updates issue fields with extended field support. */
     const updateIssueExtended = async (
         issueKey: string,
@@ -774,27 +1129,63 @@ export function useIssueDetails() {
 
     return {
         getIssue,
+        getParentIssue,
+        getIssueLinkedIssues,
+        openIssueInBrowser,
         getComments,
+        getComment,
+        getIssueChangelog,
         addComment,
+        addCommentReaction,
+        removeCommentReaction,
         updateIssue,
+        getIssueCustomFields,
+        updateIssueCustomField,
+        getIssueTags,
+        setIssueTags,
+        addIssueTag,
+        getIssueVotes,
+        voteForIssue,
+        unvoteIssue,
+        subscribeToIssue,
+        unsubscribeFromIssue,
         getAttachments,
         downloadAttachment,
         previewAttachment,
         previewInlineImage,
         getTransitions,
+        getTransitionsWithFields,
         getIssueWorklogs,
         getTodayLoggedSecondsForIssues,
+        getDailyWorklogBreakdown,
+        getWeeklySummary,
+        getIssueWorklogsInRange,
+        exportWorklogsCsv,
+        exportAllIssuesJson,
         executeTransition,
+        executeTransitionWithFields,
+        markIssueDone,
         getCachedDetails,
         clearIssueCache,
         getStatuses,
         getResolutions,
         getChecklist,
+        getChecklistProgress,
         addChecklistItem,
         editChecklistItem,
+        bulkCheckChecklistItems,
+        bulkUncheckChecklistItems,
+        duplicateChecklistItem,
         deleteChecklist,
         deleteChecklistItem,
         createIssue,
+        cloneIssue,
+        moveIssueToQueue,
+        bulkUpdateIssues,
+        getQueueFields,
+        getQueue,
+        getQueueIssueTypes,
+        getQueueComponents,
         updateIssueExtended,
         uploadAttachment,
         uploadTempAttachment,
@@ -831,6 +1222,22 @@ export function useTracker() {
         };
     }, [releaseScrollSnapshot]);
 
+    // Background refreshes load the default search, so only apply them while no custom search is active.
+    useEffect(() => {
+        const unlisten = listen<IssuePageResponse>("issue-cache-updated", (event) => {
+            if (currentOptionsRef.current !== undefined) {
+                return;
+            }
+            releaseScrollSnapshot();
+            const page = normalizeIssuePage(event.payload);
+            setIssues(page.issues);
+            setHasMore(page.hasMore);
+        });
+        return () => {
+            unlisten.then((f) => f());
+        };
+    }, [releaseScrollSnapshot]);
+
     const fetchIssues = useCallback(async (options?: IssueSearchOptions): Promise<boolean> => {
         setLoading(true);
         setError(null);
@@ -884,7 +1291,81 @@ export function useTracker() {
         }
     }, [loading, loadingMore]);
 
-    return { issues, loading, loadingMore, hasMore, error, fetchIssues, loadMore };
+    /** Preloads the next page natively so a following `loadMore` resolves without waiting on the API. */
+    const prefetchNextPage = useCallback(async () => {
+        const scrollId = nextScrollIdRef.current;
+        if (!scrollId) {
+            return;
+        }
+        const options = currentOptionsRef.current;
+        await invoke("prefetch_next_issue_page", {
+            query: options?.query ?? null,
+            filter: options?.filter ?? null,
+            scrollId,
+        });
+    }, []);
+
+    /** Searches summary/description text (at least 3 characters) without touching the paged list state. */
+    const fulltextSearchIssues = useCallback(async (text: string) => {
+        return invoke<Issue[]>("fulltext_search_issues", { text });
+    }, []);
+
+    /** Counts issues matching a query or filter with one API call, e.g. for badges. */
+    const getIssueCount = useCallback(async (query?: string | null, filter?: Record<string, unknown> | null) => {
+        return invoke<number>("get_issue_count", { query: query ?? null, filter: filter ?? null });
+    }, []);
+
+    /** Returns cached issue counts keyed by status key, without hitting the API. */
+    const getIssueStatusCounts = useCallback(async () => {
+        return invoke<Record<string, number>>("get_issue_status_counts");
+    }, []);
+
+    /** Returns cached issues in a status, without hitting the API. */
+    const findCachedIssuesByStatus = useCallback(async (statusKey: string) => {
+        return invoke<Issue[]>("find_cached_issues_by_status", { statusKey });
+    }, []);
+
+    /** Returns cached issues assigned to a display name (case-insensitive), without hitting the API. */
+    const findCachedIssuesByAssignee = useCallback(async (assigneeDisplay: string) => {
+        return invoke<Issue[]>("find_cached_issues_by_assignee", { assigneeDisplay });
+    }, []);
+
+    /** Returns the cached issues of a named saved-query store (empty for unknown names). */
+    const getNamedIssueCache = useCallback(async (name: string) => {
+        return invoke<Issue[]>("get_named_issue_cache", { name });
+    }, []);
+
+    /** Releases several stale scroll contexts, e.g. from searches left open by a closing view. */
+    const releaseScrollContexts = useCallback(async (scrollIds: string[]) => {
+        if (scrollIds.length === 0) {
+            return;
+        }
+        await invoke("release_scroll_contexts", { scrollIds });
+    }, []);
+
+    /** Runs a query and caches the result under a saved-query name; "default" also refreshes the tray. */
+    const refreshNamedCache = useCallback(async (name: string, query: string) => {
+        return invoke<Issue[]>("refresh_named_cache", { name, query });
+    }, []);
+
+    return {
+        issues,
+        loading,
+        loadingMore,
+        hasMore,
+        error,
+        fetchIssues,
+        loadMore,
+        prefetchNextPage,
+        fulltextSearchIssues,
+        getIssueCount,
+        getIssueStatusCounts,
+        findCachedIssuesByStatus,
+        findCachedIssuesByAssignee,
+        getNamedIssueCache,
+        refreshNamedCache,
+        releaseScrollContexts,
+    };
 }
 
 /**
@@ -971,20 +1452,39 @@ export function useTimer() {
         };
     }, []);
 
-    const start = async (issueKey: string, issueSummary?: string) => {
-        await invoke("start_timer", { issueKey, issueSummary: issueSummary ?? null });
+    const start = async (issueKey: string, issueSummary?: string, estimatedSeconds?: number | null) => {
+        await invoke("start_timer", {
+            issueKey,
+            issueSummary: issueSummary ?? null,
+            estimatedSeconds: estimatedSeconds ?? null,
+        });
         const newState = await invoke<TimerState>("get_timer_state");
         setState(newState);
     };
 
     const stop = async () => {
-        const result = await invoke<[number, string | null]>("stop_timer");
+        const result = await invoke<[number, string | null, boolean]>("stop_timer");
         const newState = await invoke<TimerState>("get_timer_state");
         setState(newState);
         return result;
     };
 
-    return { state, start, stop };
+    /** Moves the running timer start earlier by `offsetMinutes`; state updates arrive via `timer-tick`. */
+    const adjustStart = async (offsetMinutes: number) => {
+        await invoke("adjust_timer_start", { offsetMinutes });
+    };
+
+    /** Returns recently completed sessions, most recent first. */
+    const getSessionLog = async () => {
+        return invoke<CompletedSession[]>("get_timer_session_log");
+    };
+
+    /** Forgets all completed sessions. */
+    const clearSessionLog = async () => {
+        await invoke("clear_timer_session_log");
+    };
+
+    return { state, start, stop, adjustStart, getSessionLog, clearSessionLog };
 }
 
 /**
@@ -1039,7 +1539,13 @@ export function useClientCredentials() {
         void refresh();
     }, [refresh]);
 
-    return { info, loading, error, refresh };
+    /** Saves replacement OAuth app credentials, then reloads the public credential status. */
+    const save = useCallback(async (clientId: string, clientSecret: string) => {
+        await invoke("save_client_credentials", { clientId, clientSecret });
+        return refresh();
+    }, [refresh]);
+
+    return { info, loading, error, refresh, save };
 }
 
 /** Checks whether backend currently has an active persisted auth session. */
@@ -1094,6 +1600,19 @@ export function useConfig() {
         };
     }, []);
 
+    useEffect(() => {
+        // Native event contract: `config-changed` emitted after saves and external config.json edits.
+        const unlisten = listen<Config>("config-changed", (event) => {
+            const normalized = normalizeConfig(event.payload);
+            cachedConfig = normalized;
+            setConfig(normalized);
+        });
+
+        return () => {
+            unlisten.then((f) => f());
+        };
+    }, []);
+
     const save = async (newConfig: Config) => {
         const normalized = normalizeConfig(newConfig);
         await invoke("save_config", { config: normalized });
@@ -1109,7 +1628,40 @@ export function useConfig() {
         setConfig(data);
     };
 
-    return { config, save, refresh };
+    /** Reloads config after a native-side write and notifies other hook instances. */
+    const reloadFromNative = async () => {
+        const data = await fetchConfigCached(true);
+        setConfig(data);
+        if (typeof window !== "undefined") {
+            window.dispatchEvent(new CustomEvent<Config>(CONFIG_UPDATED_EVENT, { detail: data }));
+        }
+    };
+
+    /** Returns current settings as pretty-printed JSON for transfer to another machine. */
+    const exportConfig = async () => invoke<string>("export_config");
+
+    /** Imports settings from exported JSON; rejects unsupported schema versions. */
+    const importConfig = async (json: string) => {
+        await invoke("import_config", { json });
+        await reloadFromNative();
+    };
+
+    /** Opens a file picker and imports settings; resolves `false` when the user cancels. */
+    const importConfigFromFile = async () => {
+        const imported = await invoke<boolean>("import_config_from_file");
+        if (imported) {
+            await reloadFromNative();
+        }
+        return imported;
+    };
+
+    /** Overwrites settings with defaults; works even when the config file is corrupt. */
+    const reset = async () => {
+        await invoke("reset_config");
+        await reloadFromNative();
+    };
+
+    return { config, save, refresh, reset, exportConfig, importConfig, importConfigFromFile };
 }
 
 /**
@@ -1119,11 +1671,21 @@ export function useAuth() {
     const [loading, setLoading] = useState(false);
     const [error, setError] = useState<string | null>(null);
 
-    const exchangeCode = async (code: string, orgId: string, orgType: string): Promise<boolean> => {
+    const exchangeCode = async (
+        code: string,
+        orgId: string,
+        orgType: string,
+        codeVerifier?: string,
+    ): Promise<boolean> => {
         setLoading(true);
         setError(null);
         try {
-            await invoke("exchange_code", { code, orgId: orgId || null, orgType });
+            await invoke("exchange_code", {
+                code,
+                orgId: orgId || null,
+                orgType,
+                ...(codeVerifier ? { codeVerifier } : {}),
+            });
             return true;
         } catch (err) {
             setError(String(err));
@@ -1133,7 +1695,10 @@ export function useAuth() {
         }
     };
 
-    return { exchangeCode, loading, error };
+    /** Generates a PKCE pair; pass `code_challenge` to the authorize URL and keep `code_verifier` for the exchange. */
+    const generatePkcePair = async () => invoke<PkcePair>("generate_pkce_pair");
+
+    return { exchangeCode, generatePkcePair, loading, error };
 }
 
 /**
@@ -1188,6 +1753,27 @@ export function useAccount() {
     return { profile, loading, error, refresh, logout };
 }
 
+/**
+ * Diagnostics helpers for inspecting native runtime state.
+ */
+export function useDiagnostics() {
+    const getRateLimiterMetrics = useCallback(async () => {
+        return invoke<RateLimiterMetrics>("get_rate_limiter_metrics");
+    }, []);
+
+    /** Resolves to `false` when Tracker is unreachable; rejects on API errors such as expired auth. */
+    const checkConnectivity = useCallback(async () => {
+        return invoke<boolean>("check_connectivity");
+    }, []);
+
+    /** Works without a session, so it is safe to call from the about page and bug reports. */
+    const getVersionInfo = useCallback(async () => {
+        return invoke<VersionInfo>("get_version_info");
+    }, []);
+
+    return { getRateLimiterMetrics, checkConnectivity, getVersionInfo };
+}
+
 /**
  * Auto-update orchestration hook for checking/downloading/installing updates.
  */